@@ -0,0 +1,68 @@
+use std::{
+    cmp::{max, min},
+    fmt::Display,
+};
+
+use common::{
+    Result, Solution,
+    parse::{ParseResult, parse_token},
+};
+
+// https://adventofcode.com/2024/day/1
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Input = (Vec<usize>, Vec<usize>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1((list1, list2): &Self::Input) -> impl Display {
+        total_distance(list1, list2)
+    }
+
+    fn part2((list1, list2): &Self::Input) -> impl Display {
+        similarity(list1, list2)
+    }
+}
+
+fn parse(input: &str) -> ParseResult<(Vec<usize>, Vec<usize>)> {
+    let pairs = input
+        .lines()
+        .map(|line| {
+            let mut ids = line.split_whitespace();
+            let end = &line[line.len()..];
+            let left = ids.next().unwrap_or(end);
+            let right = ids.next().unwrap_or(end);
+            Ok((parse_token(input, left)?, parse_token(input, right)?))
+        })
+        .collect::<ParseResult<Vec<(usize, usize)>>>()?;
+    Ok(pairs.into_iter().unzip())
+}
+
+fn total_distance(list1: &[usize], list2: &[usize]) -> usize {
+    let mut sorted1 = Vec::from(list1);
+    sorted1.sort();
+    let mut sorted2 = Vec::from(list2);
+    sorted2.sort();
+
+    let dists = sorted1
+        .iter()
+        .zip(sorted2)
+        .map(|(id1, id2)| max(id1, &id2) - min(id1, &id2));
+
+    dists.sum()
+}
+
+fn similarity(list1: &[usize], list2: &[usize]) -> usize {
+    list1
+        .iter()
+        .map(|id1| id1 * list2.iter().filter(|id2| id1 == *id2).count())
+        .sum()
+}
+
+common::answer_tests!(Day01);
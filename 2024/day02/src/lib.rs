@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+use common::{
+    Result, Solution,
+    parse::{ParseResult, parse_token},
+};
+
+// https://adventofcode.com/2024/day/2
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(reports: &Self::Input) -> impl Display {
+        let verdicts = reports.iter().map(|report| safety_check(report, false));
+        verdicts.filter(|verdict| *verdict).count()
+    }
+
+    fn part2(reports: &Self::Input) -> impl Display {
+        let use_problem_dampener = true;
+        let verdicts = reports
+            .iter()
+            .map(|report| safety_check(report, use_problem_dampener));
+        verdicts.filter(|verdict| *verdict).count()
+    }
+}
+
+fn parse(input: &str) -> ParseResult<Vec<Vec<i32>>> {
+    input
+        .lines()
+        .map(|line| line.split(" "))
+        .map(|levels| levels.map(|level| parse_token(input, level)).collect())
+        .collect()
+}
+
+/// Rule 1: numbers are all increasing or all decreasing
+/// Rule 2: adjacent numbers differ by either 1, 2, or 3
+fn safety_check(report: &[i32], use_problem_dampener: bool) -> bool {
+    let decreasing = report.is_sorted_by(|a, b| a > b);
+    let increasing = report.is_sorted_by(|a, b| a < b);
+    let rule1 = decreasing || increasing;
+
+    let rule2 = report.windows(2).fold(true, |acc, neighbours| {
+        let diff = neighbours[0].abs_diff(neighbours[1]);
+        let within_range = (1..=3).contains(&diff);
+        acc && within_range
+    });
+
+    let problem_resolved = if !(rule1 && rule2) && use_problem_dampener {
+        problem_dampener(report)
+    } else {
+        false
+    };
+
+    (rule1 && rule2) || problem_resolved
+}
+
+/// build all possible reports with each one missing one level.
+/// if at least one of them passes the safety check, then the problem dampener succeeded.
+fn problem_dampener(report: &[i32]) -> bool {
+    let safes_with_dampening = (0..report.len())
+        .map(|i| {
+            let mut dampened_report = report.to_vec();
+            dampened_report.remove(i);
+            dampened_report
+        })
+        .map(|new_hope| safety_check(&new_hope, false))
+        .filter(|verdict| *verdict)
+        .count();
+    safes_with_dampening > 0
+}
+
+common::answer_tests!(Day02);
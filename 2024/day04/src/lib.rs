@@ -0,0 +1,61 @@
+use std::fmt::Display;
+
+use common::{
+    Result, Solution,
+    grid::{Grid, Pos},
+    point::Point,
+};
+
+// https://adventofcode.com/2024/day/4
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Display {
+        xmax_counter(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> impl Display {
+        grid.iter()
+            .filter(|(pos, cell)| **cell == 'A' && crossed_mas(grid, *pos))
+            .count()
+    }
+}
+
+/// Both diagonals through the 'A' read MAS, forwards or backwards.
+fn crossed_mas(grid: &Grid<char>, Point { x, y }: Pos) -> bool {
+    if x == 0 || y == 0 {
+        return false;
+    }
+    let at = |x, y| grid.get(Point::new(x, y)).copied();
+    let mas = |ends| matches!(ends, (Some('M'), Some('S')) | (Some('S'), Some('M')));
+    mas((at(x - 1, y - 1), at(x + 1, y + 1))) && mas((at(x + 1, y - 1), at(x - 1, y + 1)))
+}
+
+fn xmax_counter(grid: &Grid<char>) -> usize {
+    let horizontals = grid.rows().map(|row| row.iter().collect::<String>());
+    let verticals = grid.columns().map(|column| column.collect::<String>());
+    let diagonals = grid
+        .diagonals()
+        .map(|diagonal| diagonal.collect::<String>());
+    let anti_diagonals = grid
+        .anti_diagonals()
+        .map(|diagonal| diagonal.collect::<String>());
+
+    horizontals
+        .chain(verticals)
+        .chain(diagonals)
+        .chain(anti_diagonals)
+        .map(|s| s.matches("XMAS").count() + s.matches("SAMX").count())
+        .sum()
+}
+
+common::answer_tests!(Day04);
@@ -0,0 +1,301 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use common::{
+    Result, Solution,
+    parse::{ParseResult, parse_token, split_once},
+};
+
+pub type Rules = HashMap<u32, HashSet<u32>>;
+pub type Update = Vec<u32>;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Input = (Rules, Vec<Update>);
+
+    /// Fails unless the rules put the pages of every incorrectly-ordered update into one order.
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rules, updates) = (rules(input)?, updates(input)?);
+        for update in updates
+            .iter()
+            .filter(|update| !check_updates(&rules, update))
+        {
+            if let Err(err) = correct_update(&rules, update) {
+                let pages: Vec<String> = update.iter().map(u32::to_string).collect();
+                return Err(format!("update {}: {err}", pages.join(",")).into());
+            }
+        }
+        Ok((rules, updates))
+    }
+
+    fn part1((rules, updates): &Self::Input) -> impl Display {
+        middlepage_sums(rules, updates)
+    }
+
+    fn part2((rules, updates): &Self::Input) -> impl Display {
+        updates
+            .iter()
+            .filter(|update| !check_updates(rules, update))
+            .map(|update| correct_update(rules, update).expect("checked by parse"))
+            .map(|update| middlepage(&update))
+            .sum::<u32>()
+    }
+}
+
+/// Why the pages of an update cannot be brought into a single order by the rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderError {
+    /// Each page has to precede the next one, and the last one the first.
+    Cycle(Vec<u32>),
+    /// No rule tells which of these pages comes first.
+    Ambiguous(Vec<u32>),
+    /// The page appears more than once in the update.
+    Repeated(u32),
+}
+
+impl Display for OrderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |pages: &[u32], sep| {
+            let pages: Vec<String> = pages.iter().map(u32::to_string).collect();
+            pages.join(sep)
+        };
+        match self {
+            OrderError::Cycle(pages) => {
+                write!(
+                    f,
+                    "the rules form a cycle {} -> {}",
+                    join(pages, " -> "),
+                    pages[0]
+                )
+            }
+            OrderError::Ambiguous(pages) => {
+                write!(f, "no rule orders the pages {}", join(pages, ", "))
+            }
+            OrderError::Repeated(page) => write!(f, "page {page} appears more than once"),
+        }
+    }
+}
+
+fn middlepage_sums(rules: &Rules, updates: &[Update]) -> u32 {
+    updates
+        .iter()
+        .filter(|update| check_updates(rules, update))
+        .map(middlepage)
+        .sum::<u32>()
+}
+
+fn middlepage(update: &Update) -> u32 {
+    update[(update.len() - 1) / 2]
+}
+
+/// Orders the pages by Kahn's algorithm over the rules between them: the next page is the one
+/// no remaining page has to precede. The order is only correct if there is exactly one such page
+/// every time.
+pub fn correct_update(rules: &Rules, update: &Update) -> std::result::Result<Update, OrderError> {
+    let mut pages = HashSet::with_capacity(update.len());
+    if let Some(page) = update.iter().find(|page| !pages.insert(**page)) {
+        return Err(OrderError::Repeated(*page));
+    }
+    let successors = |page: u32| -> Vec<u32> {
+        rules
+            .get(&page)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|next| pages.contains(next) && *next != page)
+            .collect()
+    };
+    let mut predecessors: HashMap<u32, usize> = pages.iter().map(|page| (*page, 0)).collect();
+    for page in &pages {
+        for next in successors(*page) {
+            *predecessors.get_mut(&next).expect("pages of the update") += 1;
+        }
+    }
+
+    let mut ordered = Vec::with_capacity(pages.len());
+    while !predecessors.is_empty() {
+        let mut ready: Vec<u32> = predecessors
+            .iter()
+            .filter(|(_page, count)| **count == 0)
+            .map(|(page, _count)| *page)
+            .collect();
+        ready.sort_unstable();
+        let page = match ready[..] {
+            [page] => page,
+            [] => return Err(OrderError::Cycle(find_cycle(rules, &predecessors))),
+            _ => return Err(OrderError::Ambiguous(ready)),
+        };
+        predecessors.remove(&page);
+        for next in successors(page) {
+            if let Some(count) = predecessors.get_mut(&next) {
+                *count -= 1;
+            }
+        }
+        ordered.push(page);
+    }
+    Ok(ordered)
+}
+
+/// A cycle among the remaining pages, each of which still has a remaining predecessor. Walking
+/// back over predecessors must eventually revisit a page.
+fn find_cycle(rules: &Rules, remaining: &HashMap<u32, usize>) -> Vec<u32> {
+    let predecessor = |page: u32| {
+        let mut candidates: Vec<u32> = remaining
+            .keys()
+            .copied()
+            .filter(|prev| {
+                *prev != page && rules.get(prev).is_some_and(|next| next.contains(&page))
+            })
+            .collect();
+        candidates.sort_unstable();
+        candidates[0]
+    };
+
+    let mut walked = vec![*remaining.keys().min().expect("remaining pages")];
+    loop {
+        let prev = predecessor(*walked.last().expect("started with a page"));
+        if let Some(start) = walked.iter().position(|page| *page == prev) {
+            let mut cycle = walked.split_off(start);
+            cycle.reverse();
+            // walked backwards, so that each page precedes the next one
+            return cycle;
+        }
+        walked.push(prev);
+    }
+}
+
+fn check_updates(rules: &Rules, update: &Update) -> bool {
+    for i in 0..update.len() {
+        let page = update[i];
+        let following_pages = update.iter().skip(i + 1);
+        if !check_following(rules, page, following_pages) {
+            return false;
+        }
+    }
+    true
+}
+
+fn check_following<'a>(
+    rules: &Rules,
+    page: u32,
+    following_pages: impl Iterator<Item = &'a u32>,
+) -> bool {
+    following_pages.fold(true, |all_valid, following| {
+        let following_valids = rules.get(&page);
+        if following_valids.is_none() {
+            return false;
+        }
+        let following_valids = following_valids.unwrap();
+        all_valid && following_valids.contains(following)
+    })
+}
+
+fn updates(input: &str) -> ParseResult<Vec<Update>> {
+    let mut updates = vec![];
+
+    for line in input.lines() {
+        let comma = line.find(",");
+        if comma.is_none() {
+            continue;
+        }
+        let mut pages = vec![];
+
+        // comma separated numbers
+        for num in line.split(",") {
+            pages.push(parse_token(input, num)?);
+        }
+
+        updates.push(pages);
+    }
+    Ok(updates)
+}
+
+fn rules(input: &str) -> ParseResult<Rules> {
+    let mut map = HashMap::new();
+
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            break;
+        }
+
+        let (x, y) = split_once(input, line, "|")?;
+        let x = parse_token(input, x)?;
+        let y = parse_token(input, y)?;
+        match map.contains_key(&x) {
+            true => {
+                let set: &mut HashSet<u32> = map.get_mut(&x).unwrap();
+                set.insert(y);
+            }
+            false => {
+                let mut set = HashSet::new();
+                set.insert(y);
+                map.insert(x, set);
+            }
+        };
+    }
+
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules_of(pairs: &[(u32, u32)]) -> Rules {
+        let mut rules = Rules::new();
+        for (page, next) in pairs {
+            rules.entry(*page).or_default().insert(*next);
+        }
+        rules
+    }
+
+    #[test]
+    fn corrects_update() {
+        let (rules, _updates) = Day05::parse(include_str!("../example")).unwrap();
+        assert_eq!(
+            correct_update(&rules, &vec![75, 97, 47, 61, 53]),
+            Ok(vec![97, 75, 47, 61, 53])
+        );
+        assert_eq!(
+            correct_update(&rules, &vec![61, 13, 29]),
+            Ok(vec![61, 29, 13])
+        );
+    }
+
+    #[test]
+    fn reports_cycle() {
+        let rules = rules_of(&[(1, 2), (2, 3), (3, 1)]);
+        let err = correct_update(&rules, &vec![1, 2, 3]).unwrap_err();
+        assert_eq!(err, OrderError::Cycle(vec![2, 3, 1]));
+        assert_eq!(err.to_string(), "the rules form a cycle 2 -> 3 -> 1 -> 2");
+    }
+
+    #[test]
+    fn rejects_unorderable_updates() {
+        let err = Day05::parse("1|2\n2|3\n3|1\n\n1,2\n3,2,1\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "update 3,2,1: the rules form a cycle 2 -> 3 -> 1 -> 2"
+        );
+    }
+
+    #[test]
+    fn reports_unordered_pages() {
+        let rules = rules_of(&[(1, 2)]);
+        let err = correct_update(&rules, &vec![3, 2, 1]).unwrap_err();
+        assert_eq!(err, OrderError::Ambiguous(vec![1, 3]));
+        assert_eq!(err.to_string(), "no rule orders the pages 1, 3");
+        assert_eq!(
+            correct_update(&rules, &vec![2, 1, 2]),
+            Err(OrderError::Repeated(2))
+        );
+    }
+}
+
+common::answer_tests!(Day05);
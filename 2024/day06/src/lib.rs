@@ -0,0 +1,296 @@
+// https://adventofcode.com/2024/day/6
+pub type Coordinate = Point<usize>;
+
+/// Marks the cells blocked by an obstacle.
+pub type Obstacles = Grid<bool>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub coord: Coordinate,
+    pub dir: Dir,
+}
+
+#[derive(Debug)]
+enum NextPose {
+    Ongoing(Guard),
+    Final(Guard),
+}
+
+/// Obstacles of every row and every column in ascending order, so that the guard can walk up to
+/// the next obstacle in sight at once.
+pub struct ObstacleIndex {
+    width: usize,
+    height: usize,
+    rows: Vec<Vec<usize>>,
+    columns: Vec<Vec<usize>>,
+}
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use common::{
+    Result, Solution,
+    grid::Grid,
+    parse::{ParseError, ParseResult},
+    point::{
+        Dir::{self, Down, Left, Right, Up},
+        Point,
+    },
+};
+use rayon::prelude::*;
+
+pub mod render;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input = (Obstacles, Guard);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_field(input)?)
+    }
+
+    fn part1((obstacles, guard): &Self::Input) -> impl Display {
+        count_visited(guard, &ObstacleIndex::new(obstacles)).len()
+    }
+
+    fn part2((obstacles, guard): &Self::Input) -> impl Display {
+        loop_obstacles(obstacles, guard).len()
+    }
+}
+
+/// Positions where a single additional obstacle makes the guard walk in circles, row by row.
+pub fn loop_obstacles(obstacles: &Obstacles, guard: &Guard) -> Vec<Coordinate> {
+    let index = ObstacleIndex::new(obstacles);
+    // an obstacle off the original path never meets the guard
+    let mut candidates = count_visited(guard, &index);
+    candidates.remove(&guard.coord);
+    let mut looping: Vec<Coordinate> = candidates
+        .into_par_iter()
+        .filter(|obstacle| detect_loop(guard, &index, Some(*obstacle)))
+        .collect();
+    looping.sort_by_key(|coord| (coord.y, coord.x));
+    looping
+}
+
+/// Whether the guard ends up walking in circles, with an optional additional obstacle.
+fn detect_loop(guard: &Guard, index: &ObstacleIndex, extra: Option<Coordinate>) -> bool {
+    find_loop(guard, index, extra).is_some()
+}
+
+/// The turns of the circle the guard ends up walking in, with an optional additional obstacle.
+/// Each turn is the pose after turning in front of an obstacle, in the order the guard takes them.
+/// `None` if the guard leaves the field.
+///
+/// Only turns are compared: paths may cross or even turn at the same cell in another direction
+/// without forming a loop, but reaching the same turn again repeats everything after it.
+pub fn find_loop(
+    guard: &Guard,
+    index: &ObstacleIndex,
+    extra: Option<Coordinate>,
+) -> Option<Vec<Guard>> {
+    walk(guard, index, extra, |_guard, _next_guard| ())
+}
+
+/// Walks the guard until it leaves the field or reaches a turn a second time, `walked` sees each
+/// straight walk from one pose to the next. Returns the circle like [`find_loop`].
+fn walk(
+    guard: &Guard,
+    index: &ObstacleIndex,
+    extra: Option<Coordinate>,
+    mut walked: impl FnMut(&Guard, &NextPose),
+) -> Option<Vec<Guard>> {
+    let mut turns = vec![];
+    let mut seen = HashMap::new();
+    let mut guard = *guard;
+    loop {
+        let next_guard = index.next_pose(&guard, extra);
+        walked(&guard, &next_guard);
+        match next_guard {
+            NextPose::Ongoing(next) => {
+                if let Some(first) = seen.insert(next, turns.len()) {
+                    return Some(turns.split_off(first));
+                }
+                turns.push(next);
+                guard = next;
+            }
+            NextPose::Final(_) => return None,
+        }
+    }
+}
+
+fn update_visited_cells(guard: &Guard, next_guard: &NextPose, visited: &mut HashSet<Coordinate>) {
+    match &next_guard {
+        NextPose::Ongoing(next_guard) | NextPose::Final(next_guard) => {
+            match guard.dir {
+                Up => (next_guard.coord.y..=guard.coord.y).for_each(|y| {
+                    visited.insert(Coordinate {
+                        x: next_guard.coord.x,
+                        y,
+                    });
+                }),
+                Down => (guard.coord.y..=next_guard.coord.y).for_each(|y| {
+                    visited.insert(Coordinate {
+                        x: next_guard.coord.x,
+                        y,
+                    });
+                }),
+                Left => (next_guard.coord.x..=guard.coord.x).for_each(|x| {
+                    visited.insert(Coordinate {
+                        x,
+                        y: next_guard.coord.y,
+                    });
+                }),
+                Right => (guard.coord.x..=next_guard.coord.x).for_each(|x| {
+                    visited.insert(Coordinate {
+                        x,
+                        y: next_guard.coord.y,
+                    });
+                }),
+            };
+        }
+    };
+}
+
+/// Cells the guard walks over until leaving the field or closing a circle.
+pub fn count_visited(guard: &Guard, index: &ObstacleIndex) -> HashSet<Coordinate> {
+    let mut visited = HashSet::new();
+    walk(guard, index, None, |guard, next_guard| {
+        update_visited_cells(guard, next_guard, &mut visited)
+    });
+    visited
+}
+
+impl ObstacleIndex {
+    pub fn new(obstacles: &Obstacles) -> ObstacleIndex {
+        let mut index = ObstacleIndex {
+            width: obstacles.width(),
+            height: obstacles.height(),
+            rows: vec![vec![]; obstacles.height()],
+            columns: vec![vec![]; obstacles.width()],
+        };
+        // row by row keeps both in ascending order
+        for (Point { x, y }, _obstacle) in obstacles.iter().filter(|(_pos, obstacle)| **obstacle) {
+            index.rows[y].push(x);
+            index.columns[x].push(y);
+        }
+        index
+    }
+
+    /// Next pose is either in front of an obstacle or at the border as the guard leaves the field.
+    /// Guard goes into direction it points and rotates afterwards. `extra` is an obstacle in
+    /// addition to the indexed ones.
+    fn next_pose(&self, guard: &Guard, extra: Option<Coordinate>) -> NextPose {
+        let Guard {
+            coord: Point { x, y },
+            dir,
+        } = *guard;
+        // obstacles in the line of sight, as x or y coordinate
+        let (line, pos, extra) = match dir {
+            Up | Down => (&self.columns[x], y, extra.filter(|e| e.x == x).map(|e| e.y)),
+            Left | Right => (&self.rows[y], x, extra.filter(|e| e.y == y).map(|e| e.x)),
+        };
+        let ahead = match dir {
+            Up | Left => {
+                let before = line.partition_point(|o| *o < pos);
+                let indexed = before.checked_sub(1).map(|i| line[i]);
+                indexed.into_iter().chain(extra.filter(|e| *e < pos)).max()
+            }
+            Down | Right => {
+                let after = line.partition_point(|o| *o <= pos);
+                let indexed = line.get(after).copied();
+                indexed.into_iter().chain(extra.filter(|e| *e > pos)).min()
+            }
+        };
+        let stop = match (dir, ahead) {
+            (Up | Left, Some(obstacle)) => obstacle + 1,
+            (Down | Right, Some(obstacle)) => obstacle - 1,
+            // guard reaches border and leaves the field
+            (Up | Left, None) => 0,
+            (Down, None) => self.height - 1,
+            (Right, None) => self.width - 1,
+        };
+        let coord = match dir {
+            Up | Down => Point::new(x, stop),
+            Left | Right => Point::new(stop, y),
+        };
+        match ahead {
+            Some(_) => NextPose::Ongoing(Guard {
+                coord,
+                dir: dir.turn_right(),
+            }),
+            None => NextPose::Final(Guard { coord, dir }),
+        }
+    }
+}
+
+/// Returns the location of the obstacles and the pose of the guard.
+pub fn parse_field(input: &str) -> ParseResult<(Obstacles, Guard)> {
+    let field = Grid::parse(input, "one of '.#^v<>'", |cell| {
+        matches!(cell, '.' | '#' | '^' | 'v' | '<' | '>').then_some(cell)
+    })?;
+    let coord = field
+        .find(|cell| Dir::try_from(*cell).is_ok())
+        .ok_or_else(|| ParseError::new(input, &input[input.len()..], "a guard"))?;
+    let player = Guard {
+        coord,
+        dir: Dir::try_from(field[coord]).expect("found as guard"),
+    };
+
+    Ok((field.map(|cell| *cell == '#'), player))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find_loop_in(field: &str, extra: Option<Coordinate>) -> Option<Vec<Guard>> {
+        let (obstacles, guard) = parse_field(field).unwrap();
+        find_loop(&guard, &ObstacleIndex::new(&obstacles), extra)
+    }
+
+    fn turn(x: usize, y: usize, dir: Dir) -> Guard {
+        Guard {
+            coord: Point::new(x, y),
+            dir,
+        }
+    }
+
+    #[test]
+    fn finds_turns_of_loop() {
+        let turns = find_loop_in(include_str!("../example"), Some(Point::new(3, 6)));
+        assert_eq!(
+            turns,
+            Some(vec![
+                turn(4, 1, Right),
+                turn(8, 1, Down),
+                turn(8, 6, Left),
+                turn(4, 6, Up),
+            ])
+        );
+    }
+
+    #[test]
+    fn leaves_despite_revisited_cells() {
+        // crosses its own path several times
+        assert_eq!(find_loop_in(include_str!("../example"), None), None);
+        // turns twice at (4,2), then walks left through the first turn at (1,2)
+        let field = "\
+........
+.#......
+.....#..
+....#...
+........
+........
+.^......
+";
+        assert_eq!(find_loop_in(field, None), None);
+    }
+}
+
+common::answer_tests!(Day06);
@@ -0,0 +1,191 @@
+//! Drawing the patrol of the guard like the puzzle text does.
+//!
+//! Cells walked up or down are marked `|`, walked left or right `-` and `+` where the guard turns
+//! or crosses its own trail. The guard starts at `^` (or the arrow it faces), obstacles are `#` and
+//! the additional obstacle is `O`.
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use common::{
+    grid::Grid,
+    point::Dir::{Down, Left, Right, Up},
+};
+
+use crate::{Coordinate, Guard, NextPose, ObstacleIndex, Obstacles, update_visited_cells, walk};
+
+/// Pixels per cell of a frame.
+const SCALE: usize = 4;
+
+/// The map with the whole trail of the guard, which ends when the guard leaves the field or
+/// closes a circle.
+pub fn render(obstacles: &Obstacles, guard: &Guard, extra: Option<Coordinate>) -> Grid<char> {
+    let mut map = initial_map(obstacles, guard, extra);
+    draw(obstacles, guard, extra, &mut map, |_map| ());
+    map
+}
+
+/// The map after each straight walk of the guard.
+pub fn frames(obstacles: &Obstacles, guard: &Guard, extra: Option<Coordinate>) -> Vec<Grid<char>> {
+    let mut map = initial_map(obstacles, guard, extra);
+    let mut frames = vec![map.clone()];
+    draw(obstacles, guard, extra, &mut map, |map| {
+        frames.push(map.clone())
+    });
+    frames
+}
+
+/// Writes the [`frames`] as numbered PPM images into the directory, which is created if missing.
+/// Returns the number of frames.
+pub fn save_frames(
+    obstacles: &Obstacles,
+    guard: &Guard,
+    extra: Option<Coordinate>,
+    dir: &Path,
+) -> io::Result<usize> {
+    std::fs::create_dir_all(dir)?;
+    let frames = frames(obstacles, guard, extra);
+    for (i, frame) in frames.iter().enumerate() {
+        write_ppm(frame, &dir.join(format!("frame{i:04}.ppm")))?;
+    }
+    Ok(frames.len())
+}
+
+/// Writes the map as binary PPM image, each cell as a square of [`SCALE`] pixels.
+pub fn write_ppm(map: &Grid<char>, path: &Path) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    write!(
+        file,
+        "P6\n{} {}\n255\n",
+        map.width() * SCALE,
+        map.height() * SCALE
+    )?;
+    for row in map.rows() {
+        let pixels: Vec<u8> = row
+            .iter()
+            .flat_map(|cell| [colour(*cell); SCALE])
+            .flatten()
+            .collect();
+        for _ in 0..SCALE {
+            file.write_all(&pixels)?;
+        }
+    }
+    file.flush()
+}
+
+fn colour(cell: char) -> [u8; 3] {
+    match cell {
+        '#' => [200, 200, 200],
+        'O' => [230, 40, 40],
+        '|' | '-' => [240, 200, 40],
+        '+' => [255, 140, 0],
+        '^' | '>' | 'v' | '<' => [40, 200, 80],
+        _ => [20, 20, 30],
+    }
+}
+
+fn initial_map(obstacles: &Obstacles, guard: &Guard, extra: Option<Coordinate>) -> Grid<char> {
+    let mut map = obstacles.map(|obstacle| if *obstacle { '#' } else { '.' });
+    if let Some(extra) = extra {
+        map[extra] = 'O';
+    }
+    map[guard.coord] = match guard.dir {
+        Up => '^',
+        Right => '>',
+        Down => 'v',
+        Left => '<',
+    };
+    map
+}
+
+/// Draws the trail onto the map, `frame` sees the map after each straight walk.
+fn draw(
+    obstacles: &Obstacles,
+    guard: &Guard,
+    extra: Option<Coordinate>,
+    map: &mut Grid<char>,
+    mut frame: impl FnMut(&Grid<char>),
+) {
+    let start = guard.coord;
+    walk(
+        guard,
+        &ObstacleIndex::new(obstacles),
+        extra,
+        |guard, next_guard| {
+            let mut cells = HashSet::new();
+            update_visited_cells(guard, next_guard, &mut cells);
+            let straight = match guard.dir {
+                Up | Down => '|',
+                Left | Right => '-',
+            };
+            for cell in cells.into_iter().filter(|cell| *cell != start) {
+                map[cell] = match map[cell] {
+                    '.' => straight,
+                    trail if trail == straight => straight,
+                    _ => '+',
+                };
+            }
+            if let NextPose::Ongoing(turn) = next_guard
+                && turn.coord != start
+            {
+                map[turn.coord] = '+';
+            }
+            frame(map);
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_field;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn renders_trail_of_example() {
+        let (obstacles, guard) = parse_field(EXAMPLE).unwrap();
+        // the puzzle text marks every visited cell with `X`
+        let visited = render(&obstacles, &guard, None).map(|cell| match cell {
+            '|' | '-' | '+' => 'X',
+            cell => *cell,
+        });
+        assert_eq!(
+            visited.to_string(),
+            "\
+....#.....
+....XXXXX#
+....X...X.
+..#.X...X.
+..XXXXX#X.
+..X.X.X.X.
+.#XX^XXXX.
+.XXXXXXX#.
+#XXXXXXX..
+......#X.."
+        );
+    }
+
+    #[test]
+    fn renders_loop_of_example() {
+        let (obstacles, guard) = parse_field(EXAMPLE).unwrap();
+        let map = render(&obstacles, &guard, Some(Coordinate::new(3, 6)));
+        assert_eq!(
+            map.to_string(),
+            "\
+....#.....
+....+---+#
+....|...|.
+..#.|...|.
+....|..#|.
+....|...|.
+.#.O^---+.
+........#.
+#.........
+......#..."
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt::Display;
+
+use common::{
+    Result, Solution,
+    parse::{ParseError, ParseResult, parse_token, split_once},
+};
+use operator::{Operator, Undone};
+
+pub mod operator;
+
+#[derive(Debug)]
+pub struct Line {
+    test_value: u64,
+    numbers: Vec<u64>,
+}
+
+impl Line {
+    fn parse(input: &str, s: &str) -> ParseResult<Line> {
+        let (test_value, numbers) = split_once(input, s, ":")?;
+        let numbers = numbers
+            .split_whitespace()
+            .map(|num| parse_token(input, num))
+            .collect::<ParseResult<Vec<u64>>>()?;
+        if numbers.is_empty() {
+            return Err(ParseError::new(input, &s[s.len()..], "numbers"));
+        }
+
+        Ok(Line {
+            test_value: parse_token(input, test_value)?,
+            numbers,
+        })
+    }
+}
+
+/// Prints the line like the input.
+impl Display for Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:", self.test_value)?;
+        for num in &self.numbers {
+            write!(f, " {num}")?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(lines: &Self::Input) -> impl Display {
+        calibrated_sum(lines, &PART1_OPERATORS)
+    }
+
+    fn part2(lines: &Self::Input) -> impl Display {
+        calibrated_sum(lines, &PART2_OPERATORS)
+    }
+}
+
+pub const PART1_OPERATORS: [Operator; 2] = [Operator::Add, Operator::Mul];
+pub const PART2_OPERATORS: [Operator; 3] = [Operator::Add, Operator::Mul, Operator::Concat];
+
+/// A line with the operators between its numbers which give its test value.
+pub struct Equation<'a> {
+    line: &'a Line,
+    operators: Vec<Operator>,
+}
+
+/// Prints the equation like `3267: 81 + 40 * 27`.
+impl Display for Equation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.line.test_value, self.line.numbers[0])?;
+        for (op, num) in self.operators.iter().zip(&self.line.numbers[1..]) {
+            write!(f, " {op} {num}")?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_input(input: &str) -> ParseResult<Vec<Line>> {
+    input.lines().map(|line| Line::parse(input, line)).collect()
+}
+
+pub fn calibrated_sum(lines: &[Line], operators: &[Operator]) -> u64 {
+    lines
+        .iter()
+        .filter(|line| equation(line, operators).is_some())
+        .map(|line| line.test_value)
+        .sum()
+}
+
+/// Some combination of the operators giving the test value, `None` if there is none.
+pub fn equation<'a>(line: &'a Line, operators: &[Operator]) -> Option<Equation<'a>> {
+    let operators = solve_backwards(line.test_value, &line.numbers, operators)?;
+    Some(Equation { line, operators })
+}
+
+/// Undoes the operators from the right: the last number was combined with the result of the
+/// numbers before it. Most operators can be undone exactly, which prunes most of the
+/// combinations early. Where any result up to a bound would do, like for `max`, the numbers
+/// before are searched forwards instead.
+fn solve_backwards(
+    test_value: u64,
+    numbers: &[u64],
+    operators: &[Operator],
+) -> Option<Vec<Operator>> {
+    let (last, rest) = numbers.split_last()?;
+    if rest.is_empty() {
+        return (test_value == *last).then(Vec::new);
+    }
+
+    operators.iter().find_map(|&op| {
+        let mut solution = match op.undo(test_value, *last) {
+            Undone::Exact(rest_value) => solve_backwards(rest_value, rest, operators)?,
+            Undone::AtMost(bound) => solve_forwards(rest, operators, bound)?,
+            Undone::Impossible => return None,
+        };
+        solution.push(op);
+        Some(solution)
+    })
+}
+
+/// Tries every combination of the operators from the left until one gives at most the bound.
+fn solve_forwards(numbers: &[u64], operators: &[Operator], bound: u64) -> Option<Vec<Operator>> {
+    fn search(
+        value: u64,
+        numbers: &[u64],
+        operators: &[Operator],
+        bound: u64,
+        chosen: &mut Vec<Operator>,
+    ) -> bool {
+        let Some((next, rest)) = numbers.split_first() else {
+            return value <= bound;
+        };
+        operators.iter().any(|&op| {
+            let Some(value) = op.apply(value, *next) else {
+                return false;
+            };
+            chosen.push(op);
+            let found = search(value, rest, operators, bound, chosen);
+            if !found {
+                chosen.pop();
+            }
+            found
+        })
+    }
+
+    let (first, rest) = numbers.split_first()?;
+    let mut chosen = Vec::new();
+    search(*first, rest, operators, bound, &mut chosen).then_some(chosen)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The equation of the only line, printed.
+    fn solve(line: &str, operators: &[Operator]) -> Option<String> {
+        let lines = parse_input(line).unwrap();
+        equation(&lines[0], operators).map(|equation| equation.to_string())
+    }
+
+    #[test]
+    fn prints_equations() {
+        let mul_first = [Operator::Mul, Operator::Add];
+        assert_eq!(
+            solve("3267: 81 40 27", &mul_first).as_deref(),
+            Some("3267: 81 + 40 * 27")
+        );
+        assert_eq!(
+            solve("156: 15 6", &PART2_OPERATORS).as_deref(),
+            Some("156: 15 || 6")
+        );
+        assert_eq!(solve("83: 17 5", &PART2_OPERATORS), None);
+    }
+
+    #[test]
+    fn solves_with_other_operators() {
+        let sub_xor = [Operator::Sub, Operator::Xor];
+        assert_eq!(
+            solve("6: 10 3 1", &sub_xor).as_deref(),
+            Some("6: 10 - 3 - 1")
+        );
+        assert_eq!(solve("6: 5 3", &sub_xor).as_deref(), Some("6: 5 ^ 3"));
+        // `max` with the test value as right operand leaves any smaller left operand
+        let add_max = [Operator::Add, Operator::Max];
+        assert_eq!(solve("12: 3 12", &add_max).as_deref(), Some("12: 3 max 12"));
+        assert_eq!(solve("12: 13 12", &add_max), None);
+        // so does multiplying by zero
+        assert_eq!(
+            solve("5: 7 3 0 5", &PART1_OPERATORS).as_deref(),
+            Some("5: 7 + 3 * 0 + 5")
+        );
+    }
+}
+
+common::answer_tests!(Day07);
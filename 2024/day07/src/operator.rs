@@ -0,0 +1,143 @@
+//! Operators combining the numbers of an equation, always evaluated from left to right.
+use std::fmt::Display;
+
+use common::parse::{ParseError, ParseResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Mul,
+    /// Appends the decimals of the right operand to the left one, `12 || 345` is `12345`.
+    Concat,
+    Sub,
+    Xor,
+    Max,
+}
+
+/// The left operand of an operator, found from its result and right operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Undone {
+    Exact(u64),
+    /// Any left operand up to the bound gives the result.
+    AtMost(u64),
+    Impossible,
+}
+
+impl Operator {
+    pub const ALL: [Operator; 6] = [
+        Operator::Add,
+        Operator::Mul,
+        Operator::Concat,
+        Operator::Sub,
+        Operator::Xor,
+        Operator::Max,
+    ];
+
+    /// `None` if the result does not fit into `u64`, which includes negative differences.
+    pub fn apply(self, left: u64, right: u64) -> Option<u64> {
+        match self {
+            Operator::Add => left.checked_add(right),
+            Operator::Mul => left.checked_mul(right),
+            Operator::Concat => left.checked_mul(decimal_shift(right)?)?.checked_add(right),
+            Operator::Sub => left.checked_sub(right),
+            Operator::Xor => Some(left ^ right),
+            Operator::Max => Some(left.max(right)),
+        }
+    }
+
+    /// The left operand giving the result with the right operand.
+    pub fn undo(self, result: u64, right: u64) -> Undone {
+        let exact = |left: Option<u64>| left.map_or(Undone::Impossible, Undone::Exact);
+        match self {
+            Operator::Add => exact(result.checked_sub(right)),
+            // anything times zero is zero
+            Operator::Mul if right == 0 && result == 0 => Undone::AtMost(u64::MAX),
+            Operator::Mul if right == 0 => Undone::Impossible,
+            Operator::Mul => exact(result.is_multiple_of(right).then(|| result / right)),
+            Operator::Concat => exact(
+                decimal_shift(right)
+                    .filter(|shift| result % shift == right)
+                    .map(|shift| result / shift),
+            ),
+            Operator::Sub => exact(result.checked_add(right)),
+            Operator::Xor => Undone::Exact(result ^ right),
+            Operator::Max if result > right => Undone::Exact(result),
+            Operator::Max if result == right => Undone::AtMost(right),
+            Operator::Max => Undone::Impossible,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Operator::Add => "add",
+            Operator::Mul => "mul",
+            Operator::Concat => "concat",
+            Operator::Sub => "sub",
+            Operator::Xor => "xor",
+            Operator::Max => "max",
+        }
+    }
+}
+
+/// Prints the symbol of the operator, like in an equation.
+impl Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Operator::Add => "+",
+            Operator::Mul => "*",
+            Operator::Concat => "||",
+            Operator::Sub => "-",
+            Operator::Xor => "^",
+            Operator::Max => "max",
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+/// Reads a list of operators separated by commas or spaces, by name like `add` or by symbol
+/// like `+`.
+pub fn parse_operators(s: &str) -> ParseResult<Vec<Operator>> {
+    s.split([',', ' '])
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            Operator::ALL
+                .into_iter()
+                .find(|op| op.name() == token || op.to_string() == token)
+                .ok_or_else(|| {
+                    ParseError::new(s, token, "one of add, mul, concat, sub, xor or max")
+                })
+        })
+        .collect()
+}
+
+/// The factor moving a number left by the decimals of `right`.
+fn decimal_shift(right: u64) -> Option<u64> {
+    10_u64.checked_pow(right.checked_ilog10().unwrap_or(0) + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undoes_operators() {
+        for op in Operator::ALL {
+            for (left, right) in [(81, 40), (486, 6), (0, 0), (7, 7), (12, 3)] {
+                let Some(result) = op.apply(left, right) else {
+                    continue;
+                };
+                match op.undo(result, right) {
+                    Undone::Exact(undone) => assert_eq!(undone, left, "{left} {op} {right}"),
+                    Undone::AtMost(bound) => assert!(left <= bound, "{left} {op} {right}"),
+                    Undone::Impossible => panic!("{left} {op} {right} cannot be undone"),
+                }
+            }
+        }
+        assert_eq!(Operator::Concat.apply(12, 345), Some(12345));
+        assert_eq!(Operator::Max.undo(5, 7), Undone::Impossible);
+        assert_eq!(
+            parse_operators("add, * ||").unwrap(),
+            [Operator::Add, Operator::Mul, Operator::Concat]
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use common::{Result, Solution, grid::Grid, point::Point};
+
+pub type Antennas = HashMap<char, Vec<Coord>>;
+pub type Coord = Point<i64>;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Input = (Antennas, Coord);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let map: Grid<char> = input.parse()?;
+        let boundary = Coord::new(map.width() as i64, map.height() as i64);
+        Ok((parse_antennas(&map), boundary))
+    }
+
+    fn part1((antennas, boundary): &Self::Input) -> impl Display {
+        calc_antinodes(antennas, boundary).len()
+    }
+
+    fn part2((antennas, boundary): &Self::Input) -> impl Display {
+        calc_antinodes_with_harmonics(antennas, boundary).len()
+    }
+}
+
+fn calc_antinodes(antennas: &Antennas, boundary: &Coord) -> HashSet<Coord> {
+    antennas
+        .values()
+        .flat_map(|ants_freq| {
+            let cartesian = ants_freq
+                .iter()
+                .flat_map(|y| ants_freq.iter().map(move |x| (x, y)));
+            cartesian
+                .filter(|(ant1, ant2)| ant1 != ant2)
+                .flat_map(antpair_antinodes)
+                .filter(|ant| coord_within_bound(ant, boundary))
+        })
+        .collect()
+}
+
+fn calc_antinodes_with_harmonics(antennas: &Antennas, boundary: &Coord) -> HashSet<Coord> {
+    antennas
+        .values()
+        .flat_map(|ants_freq| {
+            let cartesian = ants_freq
+                .iter()
+                .flat_map(|y| ants_freq.iter().map(move |x| (x, y)));
+            cartesian
+                .filter(|(ant1, ant2)| ant1 != ant2)
+                .flat_map(|ants| antpair_antinodes_harmonics(ants, boundary))
+                .filter(|ant| coord_within_bound(ant, boundary))
+        })
+        .collect()
+}
+
+/// Boundary is non-inclusive
+fn coord_within_bound(coord: &Coord, boundary: &Coord) -> bool {
+    coord.x >= 0 && coord.x < boundary.x && coord.y >= 0 && coord.y < boundary.y
+}
+
+fn antpair_antinodes((ant1, ant2): (&Coord, &Coord)) -> Vec<Coord> {
+    let anti1 = *ant1 + (*ant2 - *ant1) * 2;
+    let anti2 = *ant2 + (*ant1 - *ant2) * 2;
+    vec![anti1, anti2]
+}
+
+fn antpair_antinodes_harmonics((ant1, ant2): (&Coord, &Coord), boundary: &Coord) -> Vec<Coord> {
+    let forwards = (0..)
+        .map(|i| *ant1 + (*ant2 - *ant1) * (2 * i))
+        .take_while(|coord| coord_within_bound(coord, boundary));
+    let backwards = (0..)
+        .map(|i| *ant1 - (*ant2 - *ant1) * (2 * i))
+        .take_while(|coord| coord_within_bound(coord, boundary));
+
+    forwards.chain(backwards).collect()
+}
+
+fn parse_antennas(map: &Grid<char>) -> Antennas {
+    let mut antennas: Antennas = HashMap::new();
+    for (pos, cell) in map.iter().filter(|(_pos, cell)| **cell != '.') {
+        let coord = Coord::new(pos.x as i64, pos.y as i64);
+        antennas.entry(*cell).or_default().push(coord);
+    }
+    antennas
+}
+
+common::answer_tests!(Day08);
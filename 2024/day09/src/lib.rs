@@ -0,0 +1,291 @@
+use std::{cmp::Reverse, collections::BinaryHeap, fmt::Display};
+
+use common::{
+    Result, Solution,
+    parse::{ParseError, ParseResult},
+    visual::{Colour, paint},
+};
+
+pub struct Day09;
+
+/// Consecutive blocks of a file, or free blocks without a file ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub len: usize,
+    pub id: Option<u64>,
+}
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Span>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(decode(input)?)
+    }
+
+    fn part1(disk: &Self::Input) -> impl Display {
+        checksum(&compact(disk))
+    }
+
+    fn part2(disk: &Self::Input) -> impl Display {
+        checksum(&compact_keepfiles(disk))
+    }
+}
+
+/// Sum of the block positions multiplied by the file IDs.
+fn checksum(files: &[Span]) -> u64 {
+    files
+        .iter()
+        .map(|file| {
+            let (start, len) = (file.start as u64, file.len as u64);
+            // start + (start + 1) + ... + (start + len - 1)
+            let positions = len * start + len * len.saturating_sub(1) / 2;
+            positions * file.id.unwrap_or(0)
+        })
+        .sum()
+}
+
+fn files(disk: &[Span]) -> Vec<Span> {
+    disk.iter()
+        .filter(|span| span.id.is_some())
+        .copied()
+        .collect()
+}
+
+/// Moves single blocks from the end to the leftmost free space. Returns the files afterwards,
+/// split where only their last blocks were moved.
+///
+/// The free spans are filled from the left with the last blocks of the last files, until the
+/// next free span is behind the last file.
+pub fn compact(disk: &[Span]) -> Vec<Span> {
+    let mut files = files(disk);
+    let mut compacted = vec![];
+    'gaps: for mut gap in disk.iter().filter(|span| span.id.is_none()).copied() {
+        while gap.len > 0 {
+            let Some(file) = files.last_mut() else {
+                break 'gaps;
+            };
+            if file.start < gap.start {
+                break 'gaps;
+            }
+            let moved = gap.len.min(file.len);
+            compacted.push(Span {
+                start: gap.start,
+                len: moved,
+                id: file.id,
+            });
+            gap.start += moved;
+            gap.len -= moved;
+            // the first blocks of the file stay
+            file.len -= moved;
+            if file.len == 0 {
+                files.pop();
+            }
+        }
+    }
+    files.extend(compacted);
+    files.sort_by_key(|file| file.start);
+    files
+}
+
+/// Moves whole files, the one with the highest ID first, to the leftmost free space they fit
+/// in. Returns the files afterwards.
+///
+/// Free spans are kept in a heap per length with the leftmost span on top, so the leftmost
+/// fitting span is on top of one of the heaps of the lengths the file fits in. The space a file
+/// leaves is never used, as the remaining files are in front of it.
+pub fn compact_keepfiles(disk: &[Span]) -> Vec<Span> {
+    let maxlen = disk.iter().map(|span| span.len).max().unwrap_or(0);
+    let mut free = vec![BinaryHeap::new(); maxlen + 1];
+    for gap in disk.iter().filter(|span| span.id.is_none()) {
+        free[gap.len].push(Reverse(gap.start));
+    }
+
+    let mut files = files(disk);
+    for file in files.iter_mut().rev() {
+        let fitting = (file.len..=maxlen)
+            .filter_map(|len| free[len].peek().map(|Reverse(start)| (*start, len)))
+            .min();
+        if let Some((start, len)) = fitting
+            && start < file.start
+        {
+            free[len].pop();
+            file.start = start;
+            if len > file.len {
+                free[len - file.len].push(Reverse(start + file.len));
+            }
+        }
+    }
+    files.sort_by_key(|file| file.start);
+    files
+}
+
+/// One entry per block, the file ID or `None` for free space.
+pub fn blocks(disk: &[Span]) -> Vec<Option<u64>> {
+    let mut filesystem = vec![];
+    for span in disk {
+        filesystem.resize(span.start, None);
+        filesystem.extend(vec![span.id; span.len]);
+    }
+    filesystem
+}
+
+/// Same as the checksum of the files, for the block by block representation.
+pub fn checksum_blocks(filesystem: &[Option<u64>]) -> u64 {
+    filesystem
+        .iter()
+        .enumerate()
+        .map(|(i, block)| match block {
+            None => 0,
+            Some(block) => i as u64 * block,
+        })
+        .sum()
+}
+
+/// Block by block version of [`compact_keepfiles`], `moved` sees the filesystem after each
+/// file moved.
+pub fn compact_keepfiles_blocks(
+    filesystem: &mut [Option<u64>],
+    mut moved: impl FnMut(&[Option<u64>]),
+) {
+    let mut file_start = filesystem.len() - 1;
+    let mut file_end = filesystem.len() - 1;
+    let mut file_id = filesystem.last().unwrap().unwrap_or(0);
+    for i in 0..filesystem.len() {
+        let irev = filesystem.len() - 1 - i;
+
+        if let Some(block) = filesystem[irev] {
+            if block != file_id {
+                if swap_emptyspace(filesystem, file_start, file_end) {
+                    moved(filesystem);
+                }
+                file_start = irev;
+                file_end = irev;
+                file_id = block;
+            } else {
+                file_start = irev;
+            }
+        }
+    }
+}
+
+/// Returns whether there was enough empty space before the file.
+fn swap_emptyspace(filesystem: &mut [Option<u64>], start: usize, end: usize) -> bool {
+    let idx_space = filesystem
+        .iter()
+        .enumerate()
+        .take_while(|(i, _)| *i < start)
+        .position(|(i, _)| {
+            filesystem
+                .iter()
+                .skip(i)
+                .take(end - start + 1)
+                .all(|b| b.is_none())
+        });
+    let Some(i) = idx_space else {
+        return false;
+    };
+    // swap empty space with block
+    for j in start..=end {
+        filesystem.swap(i + j - start, j);
+    }
+    true
+}
+
+/// Block by block version of [`compact`], `moved` sees the filesystem after each block moved.
+pub fn compact_blocks(filesystem: &mut [Option<u64>], mut moved: impl FnMut(&[Option<u64>])) {
+    for i in 0..filesystem.len() {
+        if filesystem[i].is_none() {
+            let last = filesystem.len()
+                - 1
+                - filesystem
+                    .iter()
+                    .rev()
+                    .position(|block| block.is_some())
+                    .unwrap();
+            if last <= i {
+                break;
+            }
+            // switch empty space with block
+            filesystem[i] = filesystem[last];
+            filesystem[last] = None;
+            moved(filesystem);
+        }
+    }
+}
+
+/// Blocks like the puzzle text, `.` for free space and the last digit of the file ID coloured by
+/// the ID, so that neighbouring files differ.
+pub fn render_disk(filesystem: &[Option<u64>]) -> String {
+    filesystem
+        .iter()
+        .map(|block| match block {
+            None => paint('.', Colour::Grey),
+            Some(id) => paint(id % 10, Colour::CYCLE[*id as usize % Colour::CYCLE.len()]),
+        })
+        .collect()
+}
+
+/// Spans alternate between files and free space, starting with a file. Empty spans are left out,
+/// so that free spans around an empty file are merged.
+fn decode(input: &str) -> ParseResult<Vec<Span>> {
+    let base = 10;
+    let disk_map = input.trim_end();
+    let mut disk: Vec<Span> = vec![];
+    let mut start = 0;
+    for (i, char) in disk_map.char_indices() {
+        let Some(len) = char.to_digit(base).map(|len| len as usize) else {
+            let token = &disk_map[i..i + char.len_utf8()];
+            return Err(ParseError::new(input, token, "a digit"));
+        };
+        let id = (i % 2 == 0).then_some((i / 2) as u64);
+        match disk.last_mut() {
+            _ if len == 0 => {}
+            Some(last) if last.id.is_none() && id.is_none() => last.len += len,
+            _ => disk.push(Span { start, len, id }),
+        }
+        start += len;
+    }
+    Ok(disk)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Disk maps starting with a non-empty file, like the puzzle input.
+    fn disk_map() -> impl Strategy<Value = String> {
+        (1..10_u32, prop::collection::vec(0..10_u32, 0..60)).prop_map(|(first, rest)| {
+            [first]
+                .into_iter()
+                .chain(rest)
+                .map(|len| char::from_digit(len, 10).unwrap())
+                .collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn compacts_like_blocks(disk_map in disk_map()) {
+            let disk = decode(&disk_map).unwrap();
+            let mut filesystem = blocks(&disk);
+            compact_blocks(&mut filesystem, |_filesystem| ());
+            prop_assert_eq!(checksum(&compact(&disk)), checksum_blocks(&filesystem));
+        }
+
+        #[test]
+        fn compacts_files_like_blocks(disk_map in disk_map()) {
+            let disk = decode(&disk_map).unwrap();
+            let mut filesystem = blocks(&disk);
+            compact_keepfiles_blocks(&mut filesystem, |_filesystem| ());
+            prop_assert_eq!(checksum(&compact_keepfiles(&disk)), checksum_blocks(&filesystem));
+        }
+    }
+}
+
+common::answer_tests!(Day09);
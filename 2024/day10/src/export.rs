@@ -0,0 +1,146 @@
+//! Trailheads with their summits, score and rating as JSON or CSV, for analysing the map
+//! elsewhere. Positions are written as `[x, y]` in JSON and as `x:y` in CSV.
+use std::fmt::Write;
+
+use common::grid::{Grid, Pos};
+use serde::Serialize;
+
+use crate::{Trails, trailheads, traverse};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+#[derive(Serialize)]
+pub struct Trailhead {
+    pub trailhead: [usize; 2],
+    pub summits: Vec<[usize; 2]>,
+    /// Number of summits reachable.
+    pub score: usize,
+    /// Number of distinct trails.
+    pub rating: u64,
+    /// Each trail from the trailhead to its summit, only if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trails: Option<Vec<Vec<[usize; 2]>>>,
+}
+
+/// All trailheads row by row, enumerating their trails can take long for high ratings.
+pub fn trailhead_summaries(field: &Grid<u8>, with_trails: bool) -> Vec<Trailhead> {
+    let trails = Trails::new(field);
+    trailheads(field)
+        .into_iter()
+        .map(|head| Trailhead {
+            trailhead: xy(head),
+            summits: trails.summits[head].iter().copied().map(xy).collect(),
+            score: trails.summits[head].len(),
+            rating: trails.paths[head],
+            trails: with_trails.then(|| {
+                traverse(field, head)
+                    .into_iter()
+                    .map(|hike| hike.into_iter().map(xy).collect())
+                    .collect()
+            }),
+        })
+        .collect()
+}
+
+/// One line per trailhead, or one line per trail if the trails are included.
+pub fn export(field: &Grid<u8>, format: Format, with_trails: bool) -> String {
+    let summaries = trailhead_summaries(field, with_trails);
+    match format {
+        Format::Json => serde_json::to_string_pretty(&summaries).expect("plain numbers and lists"),
+        Format::Csv if with_trails => {
+            let mut csv = "trailhead,summit,trail\n".to_string();
+            for summary in &summaries {
+                for trail in summary.trails.iter().flatten() {
+                    let summit = trail.last().expect("trails end at a summit");
+                    let _ = writeln!(
+                        csv,
+                        "{},{},{}",
+                        cell(&summary.trailhead),
+                        cell(summit),
+                        join(trail)
+                    );
+                }
+            }
+            csv
+        }
+        Format::Csv => {
+            let mut csv = "trailhead,score,rating,summits\n".to_string();
+            for summary in &summaries {
+                let _ = writeln!(
+                    csv,
+                    "{},{},{},{}",
+                    cell(&summary.trailhead),
+                    summary.score,
+                    summary.rating,
+                    join(&summary.summits)
+                );
+            }
+            csv
+        }
+    }
+}
+
+fn xy(pos: Pos) -> [usize; 2] {
+    [pos.x, pos.y]
+}
+
+fn cell([x, y]: &[usize; 2]) -> String {
+    format!("{x}:{y}")
+}
+
+/// Separated by spaces, so that the list needs no quotes.
+fn join(positions: &[[usize; 2]]) -> String {
+    positions.iter().map(cell).collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+    use crate::parse;
+
+    const EXAMPLE: &str = include_str!("../example2");
+
+    #[test]
+    fn exports_json() {
+        let field = parse(EXAMPLE).unwrap();
+        let exported: Value = serde_json::from_str(&export(&field, Format::Json, true)).unwrap();
+        let expected: Value = serde_json::from_str(
+            r#"[{
+                "trailhead": [3, 0],
+                "summits": [[0, 6], [6, 6]],
+                "score": 2,
+                "rating": 2,
+                "trails": [
+                    [[3, 0], [3, 1], [3, 2], [3, 3], [4, 3], [5, 3], [6, 3], [6, 4], [6, 5], [6, 6]],
+                    [[3, 0], [3, 1], [3, 2], [3, 3], [2, 3], [1, 3], [0, 3], [0, 4], [0, 5], [0, 6]]
+                ]
+            }]"#,
+        )
+        .unwrap();
+        assert_eq!(exported, expected);
+    }
+
+    #[test]
+    fn exports_csv() {
+        let field = parse(EXAMPLE).unwrap();
+        assert_eq!(
+            export(&field, Format::Csv, false),
+            "trailhead,score,rating,summits\n3:0,2,2,0:6 6:6\n"
+        );
+        let with_trails = export(&field, Format::Csv, true);
+        assert_eq!(
+            with_trails.lines().collect::<Vec<_>>(),
+            [
+                "trailhead,summit,trail",
+                "3:0,6:6,3:0 3:1 3:2 3:3 4:3 5:3 6:3 6:4 6:5 6:6",
+                "3:0,0:6,3:0 3:1 3:2 3:3 2:3 1:3 0:3 0:4 0:5 0:6",
+            ]
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use std::{collections::BTreeSet, fmt::Display};
+
+use common::{
+    Result, Solution,
+    grid::{Grid, Pos},
+    parse::ParseResult,
+};
+
+pub mod export;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+    const EXAMPLES: &'static [&'static str] = &["example", "example2", "example3", "example4"];
+
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(field: &Self::Input) -> impl Display {
+        traverse_all_heads(field, false)
+    }
+
+    fn part2(field: &Self::Input) -> impl Display {
+        traverse_all_heads(field, true)
+    }
+}
+
+/// Where the trails starting at each cell lead to.
+pub struct Trails {
+    /// Summits reachable from each cell, empty for cells without a trail to a summit.
+    pub summits: Grid<BTreeSet<Pos>>,
+    /// Number of distinct trails from each cell to any summit.
+    pub paths: Grid<u64>,
+}
+
+pub fn trailheads(field: &Grid<u8>) -> Vec<Pos> {
+    field
+        .iter()
+        .filter(|(_pos, cell)| **cell == 0)
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+fn traverse_all_heads(field: &Grid<u8>, part2: bool) -> u64 {
+    let trails = Trails::new(field);
+    trailheads(field)
+        .iter()
+        .map(|head| match part2 {
+            false => trails.summits[*head].len() as u64,
+            true => trails.paths[*head],
+        })
+        .sum()
+}
+
+impl Trails {
+    /// Walks down from the summits: a cell reaches all summits, and takes all trails, of its
+    /// neighbours one higher than itself. Those are complete once all higher cells are done.
+    pub fn new(field: &Grid<u8>) -> Trails {
+        let mut summits = field.map(|_height| BTreeSet::new());
+        let mut paths = field.map(|_height| 0);
+        for height in (0..=9).rev() {
+            for (pos, _height) in field.iter().filter(|(_pos, cell)| **cell == height) {
+                if height == 9 {
+                    summits[pos].insert(pos);
+                    paths[pos] = 1;
+                    continue;
+                }
+                for next in field.neighbours4(pos) {
+                    if field[next] == height + 1 {
+                        let reachable = summits[next].clone();
+                        summits[pos].extend(reachable);
+                        paths[pos] += paths[next];
+                    }
+                }
+            }
+        }
+        Trails { summits, paths }
+    }
+}
+
+/// All trails from the cell up to a summit, each from the cell to the summit.
+/// There are as many as [`Trails::paths`] counts, which may be a lot.
+pub fn traverse(field: &Grid<u8>, head: Pos) -> Vec<Vec<Pos>> {
+    let mut hikes = vec![];
+    let mut unfinished = vec![vec![head]];
+    while let Some(hike) = unfinished.pop() {
+        let pos_now = *hike.last().expect("starts with the head");
+        let height_here = field[pos_now];
+        if height_here == 9 {
+            hikes.push(hike);
+            continue;
+        }
+        // reversed, so that the first neighbour is continued first
+        let nexts: Vec<Pos> = field
+            .neighbours4(pos_now)
+            .filter(|next| field[*next] == height_here + 1)
+            .collect();
+        for next in nexts.into_iter().rev() {
+            let mut hike = hike.clone();
+            hike.push(next);
+            unfinished.push(hike);
+        }
+    }
+    hikes
+}
+
+/// Impassable tiles marked with '.' are higher than any summit.
+fn parse(input: &str) -> ParseResult<Grid<u8>> {
+    Grid::parse(input, "a height or '.'", |c| match (c, c.to_digit(10)) {
+        (_, Some(height)) => Some(height as u8),
+        ('.', None) => Some(u8::MAX),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_like_traversed_trails() {
+        let examples = [
+            include_str!("../example"),
+            include_str!("../example2"),
+            include_str!("../example3"),
+            include_str!("../example4"),
+        ];
+        for example in examples {
+            let field = parse(example).unwrap();
+            let trails = Trails::new(&field);
+            for head in trailheads(&field) {
+                let hikes = traverse(&field, head);
+                let summits: BTreeSet<Pos> =
+                    hikes.iter().map(|hike| hike[hike.len() - 1]).collect();
+                assert_eq!(trails.summits[head], summits, "{head:?}");
+                assert_eq!(trails.paths[head], hikes.len() as u64, "{head:?}");
+            }
+        }
+    }
+}
+
+common::answer_tests!(Day10);
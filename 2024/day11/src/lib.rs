@@ -0,0 +1,174 @@
+use std::{collections::HashMap, fmt::Display};
+
+use common::{
+    Result, Solution,
+    parse::{ParseResult, parse_token},
+    visual::{Colour, paint},
+};
+use rules::Rules;
+
+pub mod rules;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(stones: &Self::Input) -> impl Display {
+        match count_after(stones, 25, &Rules::puzzle()) {
+            Some(count) => count.to_string(),
+            None => "overflow".to_string(),
+        }
+    }
+
+    fn part2(stones: &Self::Input) -> impl Display {
+        match count_after(stones, 75, &Rules::puzzle()) {
+            Some(count) => count.to_string(),
+            None => "overflow".to_string(),
+        }
+    }
+}
+
+/// Number of stones after blinking, `None` if an engraving or the number overflows.
+pub fn count_after(stones: &[u64], blinks: usize, rules: &Rules) -> Option<u128> {
+    let mut counts = StoneCounts::new(stones);
+    for _ in 0..blinks {
+        counts = counts.blink(rules)?;
+    }
+    counts.total()
+}
+
+/// How many stones carry each engraving. The order of the stones never affects how they change,
+/// so stones with the same engraving are blinked at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoneCounts {
+    counts: HashMap<u64, u128>,
+}
+
+impl StoneCounts {
+    pub fn new(stones: &[u64]) -> StoneCounts {
+        let mut counts = HashMap::new();
+        for stone in stones {
+            *counts.entry(*stone).or_default() += 1;
+        }
+        StoneCounts { counts }
+    }
+
+    /// The stones after one more blink, `None` if an engraving or a count overflows.
+    pub fn blink(&self, rules: &Rules) -> Option<StoneCounts> {
+        let mut counts: HashMap<u64, u128> = HashMap::with_capacity(self.counts.len());
+        for (stone, count) in &self.counts {
+            for substone in rules.apply(*stone)? {
+                let total = counts.entry(substone).or_default();
+                *total = total.checked_add(*count)?;
+            }
+        }
+        Some(StoneCounts { counts })
+    }
+
+    /// Number of stones, `None` if it overflows.
+    pub fn total(&self) -> Option<u128> {
+        self.counts
+            .values()
+            .try_fold(0_u128, |total, count| total.checked_add(*count))
+    }
+
+    /// Number of different engravings.
+    pub fn distinct(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Number of stones per engraving, in ascending order of the engravings.
+    pub fn histogram(&self) -> Vec<(u64, u128)> {
+        let mut histogram: Vec<(u64, u128)> = self
+            .counts
+            .iter()
+            .map(|(stone, count)| (*stone, *count))
+            .collect();
+        histogram.sort_unstable();
+        histogram
+    }
+}
+
+/// Prints the histogram as `stone×count`.
+impl Display for StoneCounts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let histogram: Vec<String> = self
+            .histogram()
+            .iter()
+            .map(|(stone, count)| format!("{stone}×{count}"))
+            .collect();
+        write!(f, "{}", histogram.join(" "))
+    }
+}
+
+/// The histogram of the stones coloured by the rule they follow on the next blink, plain if none
+/// matches. Shows at most `limit` engravings, the most frequent first.
+pub fn render_stones(counts: &StoneCounts, rules: &Rules, limit: usize) -> String {
+    let mut histogram = counts.histogram();
+    histogram.sort_by_key(|(_stone, count)| std::cmp::Reverse(*count));
+    let mut rendered: Vec<String> = histogram
+        .iter()
+        .take(limit)
+        .map(|(stone, count)| {
+            let stone = match rules.matching(*stone) {
+                Some(i) => paint(stone, Colour::CYCLE[i % Colour::CYCLE.len()]),
+                None => stone.to_string(),
+            };
+            format!("{stone}×{count}")
+        })
+        .collect();
+    if histogram.len() > limit {
+        rendered.push(format!("... {} engravings in total", histogram.len()));
+    }
+    rendered.join(" ")
+}
+
+pub fn parse(input: &str) -> ParseResult<Vec<u64>> {
+    input
+        .split_whitespace()
+        .map(|s| parse_token(input, s))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_engravings_per_blink() {
+        let rules = Rules::puzzle();
+        let counts = StoneCounts::new(&[125, 17]).blink(&rules).unwrap();
+        assert_eq!(counts.histogram(), [(1, 1), (7, 1), (253000, 1)]);
+        let counts = counts.blink(&rules).unwrap();
+        assert_eq!(counts.to_string(), "0×1 253×1 2024×1 14168×1");
+        // both zeros become the same stone
+        let counts = StoneCounts::new(&[0, 0, 9]).blink(&rules).unwrap();
+        assert_eq!(counts.histogram(), [(1, 2), (18216, 1)]);
+        assert_eq!((counts.distinct(), counts.total()), (2, Some(3)));
+    }
+
+    #[test]
+    fn reports_overflow() {
+        // 19 digits are multiplied beyond u64
+        let rules = Rules::puzzle();
+        assert_eq!(StoneCounts::new(&[10_u64.pow(18)]).blink(&rules), None);
+        // every zero splits into two, doubling the count each blink
+        let doubling = Rules::parse("any -> split 10").unwrap();
+        assert_eq!(count_after(&[0], 127, &doubling), Some(1 << 127));
+        assert_eq!(count_after(&[0], 128, &doubling), None);
+        let counts = StoneCounts {
+            counts: HashMap::from([(0, 1 << 127), (1, 1 << 127)]),
+        };
+        assert_eq!(counts.total(), None);
+    }
+}
+
+common::answer_tests!(Day11);
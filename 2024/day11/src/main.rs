@@ -1,121 +1,70 @@
-use std::collections::VecDeque;
+use std::process::ExitCode;
 
-use cached::proc_macro::cached;
+use aoc2024_day11::{Day11, StoneCounts, count_after, render_stones, rules::Rules};
+use common::visual::Visualiser;
 
-fn main() {
-    for path in ["example", "input"] {
-        let input = std::fs::read_to_string(path).unwrap();
-        let mut stones = parse(&input);
-        println!("Initial stones with {path}: {:?}", stones);
+/// Path of a spec with other rules, whose stones are shown and counted besides the puzzle answers.
+const RULES_VAR: &str = "AOC_STONE_RULES";
 
-        for i in 1..6 {
-            stones = blink(&stones);
-            println!("After {i} blinks: {:?}", stones);
+/// The rules of the spec named by [`RULES_VAR`], `None` if it is not set or invalid.
+fn variant_rules() -> Option<Rules> {
+    let path = std::path::PathBuf::from(std::env::var_os(RULES_VAR)?);
+    let spec = match std::fs::read_to_string(&path) {
+        Ok(spec) => spec,
+        Err(err) => {
+            eprintln!("{} - {err}", path.display());
+            return None;
         }
-        let mut stones = parse_deque(&input);
-        for i in 1..6 {
-            blink_mut(&mut stones);
-            println!("After {i} blinks: {:?}", stones);
+    };
+    match Rules::parse(&spec) {
+        Ok(rules) => Some(rules),
+        Err(err) => {
+            eprintln!("{}", err.with_file(&path));
+            None
         }
-
-        let stones = parse(&input);
-        let start = std::time::Instant::now();
-        let i = 75;
-        let nstones = blink_stone_counter(&stones, i);
-        let end = std::time::Instant::now();
-        println!(
-            "There are {} stones after blinking {i} times (computed within {:?})",
-            nstones,
-            end - start
-        );
     }
 }
 
-fn blink(stones: &[u64]) -> Vec<u64> {
-    stones.iter().copied().flat_map(blink_stone).collect()
-}
-
-fn blink_stone_counter(stones: &[u64], depth: usize) -> usize {
-    stones
-        .iter()
-        .map(|stone| nstones_depth(*stone, depth))
-        .sum()
-}
-
-#[cached]
-fn nstones_depth(stone: u64, depth: usize) -> usize {
-    let mut nstones = 0;
-    if depth == 1 {
-        let substones = blink_stone(stone);
-        return substones.len();
-    }
-
-    for substone in blink_stone(stone) {
-        nstones += nstones_depth(substone, depth - 1);
-    }
-
-    nstones
-}
+fn main() -> ExitCode {
+    let variant = variant_rules();
+    // one for all sources, as it keeps reading the commands on stdin
+    let mut visualiser = Visualiser::from_env();
+    common::main_with::<Day11>(|source, stones| {
+        println!("Initial stones with {source}: {:?}", stones);
 
-fn blink_stone(stone: u64) -> Vec<u64> {
-    match stone {
-        0 => vec![1],
-        _ => {
-            if numdigits_even(stone) {
-                vec![stone * 2024]
-            } else {
-                // cut number in halfes
-                vec![
-                    stone / 10_u64.pow(numdigits(stone) / 2 + 1),
-                    stone % 10_u64.pow(numdigits(stone) / 2 + 1),
-                ]
-            }
+        let mut shown = visualiser.as_mut();
+        if let Some(shown) = &mut shown {
+            shown.restart();
         }
-    }
-}
-
-fn blink_mut(stones: &mut VecDeque<u64>) {
-    let mut nconcats = 0;
-    for i in 0..stones.len() {
-        let stone = stones[i + nconcats];
-        match stone {
-            0 => stones[i + nconcats] = 1,
-            _ => {
-                if numdigits_even(stone) {
-                    stones[i + nconcats] *= 2024;
-                } else {
-                    // cut number in halfes
-                    stones[i + nconcats] = stone / 10_u64.pow(numdigits(stone) / 2 + 1);
-                    stones.insert(
-                        i + 1 + nconcats,
-                        stone % 10_u64.pow(numdigits(stone) / 2 + 1),
-                    );
-                    nconcats += 1;
+        let rules = variant.clone().unwrap_or_else(Rules::puzzle);
+        let mut counts = StoneCounts::new(stones);
+        for i in 1..=25 {
+            let Some(next) = counts.blink(&rules) else {
+                eprintln!("{source} - overflow after {i} blinks");
+                break;
+            };
+            counts = next;
+            if i < 6 {
+                println!("After {i} blinks: {counts}");
+            }
+            if let Some(visualiser) = &mut shown {
+                let title = format!(
+                    "{source} - after {i} blinks, {} engravings",
+                    counts.distinct()
+                );
+                if !visualiser.show(title, render_stones(&counts, &rules, 1000)) {
+                    shown = None;
                 }
             }
         }
-    }
-}
-
-fn parse(input: &str) -> Vec<u64> {
-    input
-        .split_whitespace()
-        .map(|s| s.parse().unwrap())
-        .collect()
-}
-fn parse_deque(input: &str) -> VecDeque<u64> {
-    input
-        .split_whitespace()
-        .map(|s| s.parse().unwrap())
-        .collect()
-}
-
-#[inline]
-fn numdigits_even(x: u64) -> bool {
-    x.ilog10().is_multiple_of(2)
-}
 
-#[inline]
-fn numdigits(x: u64) -> u32 {
-    x.ilog10()
+        if let Some(variant) = &variant {
+            for blinks in [25, 75] {
+                let count = count_after(stones, blinks, variant)
+                    .map_or("overflow".to_string(), |count| count.to_string());
+                println!("{source} - variant after {blinks} blinks: {count}");
+            }
+        }
+        Ok(common::answers::<Day11>(stones))
+    })
 }
@@ -0,0 +1,220 @@
+//! Rules for changing stones as data, to explore variants of the puzzle.
+//!
+//! A spec lists one rule per line as `<predicate> -> <transformation>`, the first rule whose
+//! predicate matches the stone applies. Empty lines and lines starting with `#` are ignored.
+//!
+//! Predicates are `zero`, `even-digits <base>`, `divisible <n>` and `any`.
+//! Transformations are `replace <n>`, `split <base>` (into the halves of its digits, the right one
+//! being shorter for an odd number of digits), `multiply <n>` and `add <n>`.
+use std::fmt::Display;
+
+use common::parse::{ParseError, ParseResult, parse_token, split_once};
+
+/// The rules of the puzzle.
+pub const PUZZLE_RULES: &str = "\
+zero -> replace 1
+even-digits 10 -> split 10
+any -> multiply 2024
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Predicate {
+    Zero,
+    EvenDigits { base: u64 },
+    Divisible(u64),
+    Any,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transformation {
+    Replace(u64),
+    Split { base: u64 },
+    Multiply(u64),
+    Add(u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    pub predicate: Predicate,
+    pub transformation: Transformation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    rules: Vec<Rule>,
+}
+
+impl Predicate {
+    fn matches(self, stone: u64) -> bool {
+        match self {
+            Predicate::Zero => stone == 0,
+            Predicate::EvenDigits { base } => numdigits(stone, base).is_multiple_of(2),
+            Predicate::Divisible(n) => stone.is_multiple_of(n),
+            Predicate::Any => true,
+        }
+    }
+}
+
+impl Transformation {
+    /// The stones replacing the stone, `None` if an engraving overflows.
+    fn apply(self, stone: u64) -> Option<Vec<u64>> {
+        match self {
+            Transformation::Replace(n) => Some(vec![n]),
+            Transformation::Split { base } => {
+                let half = base.checked_pow(numdigits(stone, base) / 2)?;
+                Some(vec![stone / half, stone % half])
+            }
+            Transformation::Multiply(n) => Some(vec![stone.checked_mul(n)?]),
+            Transformation::Add(n) => Some(vec![stone.checked_add(n)?]),
+        }
+    }
+}
+
+impl Rules {
+    pub fn puzzle() -> Rules {
+        Rules::parse(PUZZLE_RULES).expect("valid rules")
+    }
+
+    pub fn parse(spec: &str) -> ParseResult<Rules> {
+        let rules = spec
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let (predicate, transformation) = split_once(spec, line, "->")?;
+                Ok(Rule {
+                    predicate: parse_predicate(spec, predicate.trim())?,
+                    transformation: parse_transformation(spec, transformation.trim())?,
+                })
+            })
+            .collect::<ParseResult<Vec<Rule>>>()?;
+        Ok(Rules { rules })
+    }
+
+    /// Index of the rule the stone follows, `None` if no rule matches.
+    pub fn matching(&self, stone: u64) -> Option<usize> {
+        self.rules
+            .iter()
+            .position(|rule| rule.predicate.matches(stone))
+    }
+
+    /// The stones replacing the stone, `None` if an engraving overflows. Stones matching no
+    /// rule stay as they are.
+    pub fn apply(&self, stone: u64) -> Option<Vec<u64>> {
+        match self.matching(stone) {
+            Some(i) => self.rules[i].transformation.apply(stone),
+            None => Some(vec![stone]),
+        }
+    }
+}
+
+/// Prints the rules like their spec.
+impl Display for Rules {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for rule in &self.rules {
+            match rule.predicate {
+                Predicate::Zero => write!(f, "zero")?,
+                Predicate::EvenDigits { base } => write!(f, "even-digits {base}")?,
+                Predicate::Divisible(n) => write!(f, "divisible {n}")?,
+                Predicate::Any => write!(f, "any")?,
+            }
+            match rule.transformation {
+                Transformation::Replace(n) => writeln!(f, " -> replace {n}")?,
+                Transformation::Split { base } => writeln!(f, " -> split {base}")?,
+                Transformation::Multiply(n) => writeln!(f, " -> multiply {n}")?,
+                Transformation::Add(n) => writeln!(f, " -> add {n}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_predicate(spec: &str, s: &str) -> ParseResult<Predicate> {
+    let expected = "zero, even-digits <base>, divisible <n> or any";
+    match s.split_whitespace().collect::<Vec<_>>()[..] {
+        ["zero"] => Ok(Predicate::Zero),
+        ["even-digits", base] => Ok(Predicate::EvenDigits {
+            base: parse_base(spec, base)?,
+        }),
+        ["divisible", n] => match parse_token(spec, n)? {
+            0 => Err(ParseError::new(spec, n, "a divisor above 0")),
+            n => Ok(Predicate::Divisible(n)),
+        },
+        ["any"] => Ok(Predicate::Any),
+        _ => Err(ParseError::new(spec, s, expected)),
+    }
+}
+
+fn parse_transformation(spec: &str, s: &str) -> ParseResult<Transformation> {
+    let expected = "replace <n>, split <base>, multiply <n> or add <n>";
+    match s.split_whitespace().collect::<Vec<_>>()[..] {
+        ["replace", n] => Ok(Transformation::Replace(parse_token(spec, n)?)),
+        ["split", base] => Ok(Transformation::Split {
+            base: parse_base(spec, base)?,
+        }),
+        ["multiply", n] => Ok(Transformation::Multiply(parse_token(spec, n)?)),
+        ["add", n] => Ok(Transformation::Add(parse_token(spec, n)?)),
+        _ => Err(ParseError::new(spec, s, expected)),
+    }
+}
+
+fn parse_base(spec: &str, s: &str) -> ParseResult<u64> {
+    match parse_token(spec, s)? {
+        0 | 1 => Err(ParseError::new(spec, s, "a base of at least 2")),
+        base => Ok(base),
+    }
+}
+
+/// Number of digits of the stone written in the base, `0` has one digit.
+fn numdigits(stone: u64, base: u64) -> u32 {
+    stone.checked_ilog(base).unwrap_or(0) + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The rules as the puzzle text states them.
+    fn blink_stone(stone: u64) -> Vec<u64> {
+        let digits = stone.to_string();
+        match stone {
+            0 => vec![1],
+            _ if digits.len().is_multiple_of(2) => {
+                let (left, right) = digits.split_at(digits.len() / 2);
+                vec![left.parse().unwrap(), right.parse().unwrap()]
+            }
+            _ => vec![stone * 2024],
+        }
+    }
+
+    #[test]
+    fn matches_puzzle_rules() {
+        let rules = Rules::puzzle();
+        let stones = (0..100_000).chain([253000, 28676032, 1036288, u32::MAX.into()]);
+        for stone in stones {
+            assert_eq!(rules.apply(stone), Some(blink_stone(stone)), "{stone}");
+        }
+        assert_eq!(rules.to_string(), PUZZLE_RULES);
+    }
+
+    #[test]
+    fn splits_odd_digits() {
+        let rules = Rules::parse("any -> split 10").unwrap();
+        assert_eq!(rules.apply(123), Some(vec![12, 3]));
+        assert_eq!(rules.apply(10_002), Some(vec![100, 2]));
+        // 0b101 into 0b10 and 0b1
+        let rules = Rules::parse("any -> split 2").unwrap();
+        assert_eq!(rules.apply(5), Some(vec![2, 1]));
+    }
+
+    #[test]
+    fn reports_invalid_rules() {
+        let err = Rules::parse("zero -> replace 1\nodd -> add 1\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "2:1: expected zero, even-digits <base>, divisible <n> or any, found \"odd\""
+        );
+        // 19 digits are multiplied beyond u64
+        assert_eq!(Rules::puzzle().apply(10_u64.pow(18)), None);
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+
+use common::{
+    Result, Solution,
+    parse::{ParseError, ParseResult, parse_token},
+};
+
+/// Initial position of the dial.
+const DIAL: i32 = 50;
+
+pub struct Rot {
+    dir: char,
+    dist: i32,
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Rot>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(rotations: &Self::Input) -> impl Display {
+        password_part1(DIAL, rotations)
+    }
+
+    fn part2(rotations: &Self::Input) -> impl Display {
+        password_part2(DIAL, rotations)
+    }
+}
+
+fn password_part1(init: i32, rotations: &[Rot]) -> u32 {
+    let (_dial, passwd) = rotations.iter().fold((init, 0), |(dial, passwd), rot| {
+        let newstate = match rot.dir {
+            'L' => dial - rot.dist,
+            _ => dial + rot.dist,
+        };
+
+        let newdial = match newstate % 100 {
+            100 => 0,
+            0.. => newstate % 100,               // positive newdial
+            ..0 => 100 - (newstate.abs() % 100), // negative newdial
+        };
+
+        let passwd = if newdial == 0 { passwd + 1 } else { passwd };
+        (newdial, passwd)
+    });
+    passwd
+}
+
+fn password_part2(init: i32, rotations: &[Rot]) -> u32 {
+    let (_dial, passwd) = rotations.iter().fold((init, 0), |(dial, passwd), rot| {
+        let newstate = match rot.dir {
+            'L' => dial - rot.dist,
+            _ => dial + rot.dist,
+        };
+
+        let clicks0 = match rot.dir {
+            'L' => {
+                if dial == 0 {
+                    // if current dial is 0, it was counted with the last rotation already. skip this one
+                    (dial + rot.dist) as u32 / 100
+                } else {
+                    ((100 - dial) + rot.dist) as u32 / 100
+                }
+            }
+            _ => newstate as u32 / 100,
+        };
+
+        let newdial = match newstate % 100 {
+            100 => 0,
+            0.. => newstate % 100,               // positive newdial
+            ..0 => 100 - (newstate.abs() % 100), // negative newdial
+        };
+
+        (newdial, passwd + clicks0)
+    });
+    passwd
+}
+
+fn parse(input: &str) -> ParseResult<Vec<Rot>> {
+    input
+        .lines()
+        .map(|line| {
+            let (dir, dist) = match line.chars().next() {
+                Some(dir @ ('L' | 'R')) => (dir, &line[1..]),
+                _ => return Err(ParseError::new(input, line, "a rotation like L68")),
+            };
+            Ok(Rot {
+                dir,
+                dist: parse_token(input, dist)?,
+            })
+        })
+        .collect()
+}
+
+common::answer_tests!(Day01);
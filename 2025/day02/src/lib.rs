@@ -0,0 +1,88 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use common::{
+    Result, Solution,
+    parse::{ParseResult, parse_token, split_once},
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    type Input = Vec<RangeInclusive<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(ranges: &Self::Input) -> impl Display {
+        ranges.iter().flat_map(invalid_ids_part1).sum::<u64>()
+    }
+
+    fn part2(ranges: &Self::Input) -> impl Display {
+        ranges.iter().flat_map(invalid_ids_part2).sum::<u64>()
+    }
+}
+
+fn invalid_ids_part1(range: &RangeInclusive<u64>) -> Vec<u64> {
+    range
+        .clone()
+        .filter(|num| repeats_twice(*num))
+        .filter(|num| range.contains(num))
+        .collect()
+}
+
+fn invalid_ids_part2(range: &RangeInclusive<u64>) -> Vec<u64> {
+    range
+        .clone()
+        .filter(|num| repeats_atleast_twice(*num))
+        .filter(|num| range.contains(num))
+        .collect()
+}
+
+fn repeats_twice(num: u64) -> bool {
+    let num_digits = num.ilog10() + 1;
+
+    match num_digits.is_multiple_of(2) {
+        true => {
+            let higher_half = num / 10_u64.pow(num_digits / 2);
+            let lower_half = num % 10_u64.pow(num_digits / 2);
+            higher_half == lower_half
+        }
+        false => false,
+    }
+}
+
+fn repeats_atleast_twice(num: u64) -> bool {
+    let num_digits = num.ilog10() + 1;
+    // Possible are repetitions of the numbers in the higher 'half' of the number.
+    let possible_reps = (0..(num_digits / 2)).map(|i| (i, num / 10_u64.pow(num_digits - i - 1)));
+    // concatenations are the repetitions of the numbers
+    let mut concatenations = possible_reps.map(|(i, num)| repeat_num(num, num_digits / (i + 1)));
+    concatenations.any(|concat| concat == num)
+}
+
+// concats num n times
+fn repeat_num(num: u64, n: u32) -> u64 {
+    let subnum_digits = num.ilog10() + 1;
+    let mut ret = 0;
+    for i in 0..n {
+        ret += num * 10_u64.pow(i * subnum_digits);
+    }
+    ret
+}
+
+fn parse(input: &str) -> ParseResult<Vec<RangeInclusive<u64>>> {
+    input
+        .trim()
+        .split(',')
+        .map(|range| {
+            let (start, end) = split_once(input, range.trim(), "-")?;
+            Ok(parse_token(input, start)?..=parse_token(input, end)?)
+        })
+        .collect()
+}
+
+common::answer_tests!(Day02);
@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+use common::{
+    Result, Solution,
+    parse::{ParseError, ParseResult},
+};
+
+/// Number of batteries turned on in each bank for part 2, and so the least a bank has.
+const BATTERIES: u64 = 12;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(banks: &Self::Input) -> impl Display {
+        banks.iter().map(|bank| maxjoltage_part1(bank)).sum::<u64>()
+    }
+
+    fn part2(banks: &Self::Input) -> impl Display {
+        banks
+            .iter()
+            .map(|bank| maxjoltage_part2(bank, BATTERIES))
+            .sum::<u64>()
+    }
+}
+
+/// n: number of batteries used (i.e., number of digits within bank).
+fn maxjoltage_part2(bank: &[u8], n: u64) -> u64 {
+    let mut skip = 0;
+    let mut maxjoltage = 0;
+    for i in 0..n {
+        let digit = n - i - 1;
+        let take = bank.len() - digit as usize - skip;
+        let (maxpos_new, maxval) = findmax(bank, skip, take);
+        skip = maxpos_new + 1;
+        maxjoltage += 10_u64.pow(digit as u32) * maxval;
+    }
+    maxjoltage
+}
+
+fn findmax(bank: &[u8], skip: usize, take: usize) -> (usize, u64) {
+    let (max_pos, max_val) = bank
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .take(take) // do not use the last elements, as it could be needed later
+        .rev() // max() and max_by_key() return the last max element
+        .max_by_key(|(_i, val)| **val)
+        .unwrap();
+    (max_pos, *max_val as u64)
+}
+
+fn maxjoltage_part1(bank: &[u8]) -> u64 {
+    let (max_pos, max_val) = bank
+        .iter()
+        .take(bank.len() - 1) // do not use the last element, as it could be needed later
+        .rev() // max() and max_by_key() return the last max element
+        .enumerate()
+        .max_by_key(|(_i, val)| **val)
+        .unwrap();
+    // handle the reverse order search
+    let max_pos = bank.len() - 1 - max_pos;
+
+    let (_second_max_pos, second_max_val) = bank
+        .iter()
+        .skip(max_pos)
+        .take(bank.len())
+        .rev()
+        .enumerate()
+        .max_by_key(|(_i, val)| **val)
+        .unwrap();
+
+    let joltage = max_val * 10 + second_max_val;
+    joltage as u64
+}
+
+fn parse(input: &str) -> ParseResult<Vec<Vec<u8>>> {
+    input
+        .lines()
+        .map(|line| {
+            let bank = line
+                .char_indices()
+                .map(|(i, c)| match c.to_digit(10) {
+                    Some(joltage) => Ok(joltage as u8),
+                    None => Err(ParseError::new(
+                        input,
+                        &line[i..i + c.len_utf8()],
+                        "a joltage digit",
+                    )),
+                })
+                .collect::<ParseResult<Vec<u8>>>()?;
+            if (bank.len() as u64) < BATTERIES {
+                let expected = format!("a bank of at least {BATTERIES} joltage digits");
+                return Err(ParseError::new(input, line, expected));
+            }
+            Ok(bank)
+        })
+        .collect()
+}
+
+common::answer_tests!(Day03);
@@ -0,0 +1,95 @@
+use std::fmt::Display;
+
+use common::{
+    Result, Solution,
+    grid::{Grid, Pos},
+    parse::ParseResult,
+    visual::{Colour, paint},
+};
+
+/// Marks the cells holding a paper roll.
+pub type Rolls = Grid<bool>;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Input = Rolls;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_rolls(input)?)
+    }
+
+    fn part1(rolls: &Self::Input) -> impl Display {
+        accessible_rolls_part1(rolls)
+    }
+
+    fn part2(rolls: &Self::Input) -> impl Display {
+        accessible_rolls_part2(rolls, 0)
+    }
+}
+
+fn accessible_rolls_part1(rolls: &Rolls) -> usize {
+    accessibles(rolls).count()
+}
+
+fn accessible_rolls_part2(rolls: &Rolls, previously_removed: usize) -> usize {
+    let toremove = removal_wave(rolls);
+
+    // Call recursively
+    match toremove.len() {
+        0 => previously_removed,
+        _ => {
+            let mut trimmed = rolls.clone();
+            for pos in &toremove {
+                trimmed[*pos] = false;
+            }
+            accessible_rolls_part2(&trimmed, previously_removed + toremove.len())
+        }
+    }
+}
+
+/// Rolls which can be removed at once, as they are accessible before any of them is removed.
+pub fn removal_wave(rolls: &Rolls) -> Vec<Pos> {
+    accessibles(rolls).collect()
+}
+
+/// The rolls (@) with the ones of the wave highlighted, as they are about to be removed.
+pub fn render_wave(rolls: &Rolls, wave: &[Pos]) -> String {
+    let mut map = rolls.map(|roll| match roll {
+        true => "@".to_string(),
+        false => paint('.', Colour::Grey),
+    });
+    for pos in wave {
+        map[*pos] = paint('x', Colour::Red);
+    }
+    map.to_string()
+}
+
+/// Rolls with fewer than four rolls around them.
+fn accessibles(rolls: &Rolls) -> impl Iterator<Item = Pos> {
+    rolls
+        .iter()
+        .filter(|(pos, roll)| **roll && num_neighbours(rolls, *pos) < 4)
+        .map(|(pos, _roll)| pos)
+}
+
+fn num_neighbours(rolls: &Rolls, pos: Pos) -> usize {
+    rolls
+        .neighbours8(pos)
+        .filter(|neighbour| rolls[*neighbour])
+        .count()
+}
+
+/// Marks the paper rolls (@)
+fn parse_rolls(input: &str) -> ParseResult<Rolls> {
+    Grid::parse(input, "'@' or '.'", |cell| match cell {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+common::answer_tests!(Day04);
@@ -0,0 +1,116 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use common::{
+    Result, Solution,
+    parse::{ParseResult, parse_token, split_once},
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Input = (Vec<RangeInclusive<u64>>, Vec<u64>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((parse_fresh(input)?, parse_availables(input)?))
+    }
+
+    fn part1((freshranges, availables): &Self::Input) -> impl Display {
+        available_and_fresh(availables, freshranges)
+    }
+
+    fn part2((freshranges, _availables): &Self::Input) -> impl Display {
+        num_fresh_ids(&mut freshranges.clone())
+    }
+}
+
+/// Iterate and merge ranges. Ranges are merged when one range contains the start or end of another range.
+fn num_fresh_ids(freshranges: &mut Vec<RangeInclusive<u64>>) -> u64 {
+    dedup_ranges(freshranges);
+
+    // Sum up lengths of all ranges.
+    freshranges
+        .iter()
+        .map(|range| {
+            // +1 because the end of the range is inclusive.
+            range.end() + 1 - range.start()
+        })
+        .sum()
+}
+
+/// Iterate and merge ranges. Ranges are merged when one range contains the start or end of another range.
+fn dedup_ranges(freshranges: &mut Vec<RangeInclusive<u64>>) {
+    let mut i = 0;
+    while i < freshranges.len() {
+        let range_current = &freshranges[i];
+        match freshranges.iter().take(i).position(|range_prev| {
+            range_prev.contains(range_current.start())
+                || range_current.contains(range_prev.start())
+                || range_prev.contains(range_current.end())
+                || range_current.contains(range_prev.end())
+        }) {
+            Some(j) => {
+                // merge range[i] into range[j]
+                freshranges[j] = merge_ranges(&freshranges[j], &freshranges[i]);
+                freshranges.remove(i);
+                // reset loop iteration, as the change might enables new merges of ranges below i
+                i = j + 1
+            }
+            None => i += 1,
+        }
+    }
+}
+
+fn merge_ranges(range1: &RangeInclusive<u64>, range2: &RangeInclusive<u64>) -> RangeInclusive<u64> {
+    use std::cmp::{max, min};
+    let start = min(range1.start(), range2.start());
+    let end = max(range1.end(), range2.end());
+    *start..=*end
+}
+
+fn available_and_fresh(availables: &[u64], freshranges: &[RangeInclusive<u64>]) -> usize {
+    availables
+        .iter()
+        .map(|available| {
+            freshranges
+                .iter()
+                .any(|freshrange| freshrange.contains(available))
+        })
+        .filter(|available_and_fresh| *available_and_fresh)
+        .count()
+}
+
+/// The fresh ranges and the available IDs are separated by an empty line.
+fn sections(input: &str) -> (Vec<&str>, Vec<&str>) {
+    let mut lines = input.lines();
+    let fresh_ranges = lines
+        .by_ref()
+        .take_while(|line| !line.trim().is_empty())
+        .collect();
+    (fresh_ranges, lines.collect())
+}
+
+fn parse_fresh(input: &str) -> ParseResult<Vec<RangeInclusive<u64>>> {
+    let (fresh_ranges, _) = sections(input);
+
+    fresh_ranges
+        .into_iter()
+        .map(|line| {
+            let (start, end) = split_once(input, line, "-")?;
+            Ok(parse_token(input, start)?..=parse_token(input, end)?)
+        })
+        .collect()
+}
+
+fn parse_availables(input: &str) -> ParseResult<Vec<u64>> {
+    let (_, availables) = sections(input);
+
+    availables
+        .into_iter()
+        .map(|line| parse_token(input, line))
+        .collect()
+}
+
+common::answer_tests!(Day05);
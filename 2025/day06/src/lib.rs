@@ -0,0 +1,157 @@
+use std::fmt::Display;
+
+use common::{
+    Result, Solution,
+    parse::{ParseError, ParseResult, parse_token},
+};
+
+/// Could be prettier but I am short on time today.
+///
+#[derive(Debug)]
+pub enum Op {
+    Add,
+    Mul,
+}
+
+impl Op {
+    fn parse(input: &str, s: &str) -> ParseResult<Op> {
+        match s {
+            "+" => Ok(Op::Add),
+            "*" => Ok(Op::Mul),
+            _ => Err(ParseError::new(input, s, "'+' or '*'")),
+        }
+    }
+}
+
+pub struct Day06;
+
+/// Both parts read the numbers differently, so the worksheet is kept in both shapes.
+pub struct Worksheet {
+    rows: Vec<Vec<u64>>,
+    columns: Vec<Vec<u64>>,
+    ops: Vec<Op>,
+}
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rows, ops) = parse_part1(input)?;
+        let columns = parse_part2(input);
+        Ok(Worksheet { rows, columns, ops })
+    }
+
+    fn part1(worksheet: &Self::Input) -> impl Display {
+        solve_part1(&worksheet.rows, &worksheet.ops)
+    }
+
+    fn part2(worksheet: &Self::Input) -> impl Display {
+        solve_part2(&worksheet.columns, &worksheet.ops)
+    }
+}
+
+fn solve_part1(numbers_rows: &[Vec<u64>], ops: &[Op]) -> u64 {
+    let ncols = ops.len();
+
+    (0..ncols)
+        .map(|col| {
+            let init = match ops[col] {
+                Op::Add => 0,
+                Op::Mul => 1,
+            };
+            numbers_rows.iter().fold(init, |acc, row| match ops[col] {
+                Op::Add => acc + row[col],
+                Op::Mul => acc * row[col],
+            })
+        })
+        .sum()
+}
+
+fn solve_part2(numbers_all: &[Vec<u64>], ops: &[Op]) -> u64 {
+    numbers_all
+        .iter()
+        .zip(ops)
+        .map(|(numbers, op)| {
+            let init = match op {
+                Op::Add => 0,
+                Op::Mul => 1,
+            };
+            numbers.iter().fold(init, |acc, row| match op {
+                Op::Add => acc + row,
+                Op::Mul => acc * row,
+            })
+        })
+        .sum()
+}
+
+fn parse_part2(input: &str) -> Vec<Vec<u64>> {
+    let input = input.trim();
+    let ncols = input.lines().next().map_or(0, str::len);
+
+    let mut numbers = vec![];
+    let mut numbers_same_op = vec![];
+    for col in 0..ncols {
+        if input
+            .lines()
+            .all(|line| line.chars().nth(col).is_none_or(char::is_whitespace))
+        {
+            // new operation
+            numbers.push(numbers_same_op.clone());
+            numbers_same_op.clear();
+            continue;
+        }
+
+        let mut digit = 0;
+        let mut num = 0;
+        for rowstr in input.lines().rev().skip(1) {
+            match rowstr.chars().nth(col).and_then(|c| c.to_digit(10)) {
+                Some(n) => {
+                    num += n as u64 * 10_u64.pow(digit as u32);
+                    digit += 1;
+                }
+                None => continue,
+            }
+        }
+
+        numbers_same_op.push(num);
+    }
+    numbers.push(numbers_same_op.clone());
+    numbers
+}
+
+fn parse_part1(input: &str) -> ParseResult<(Vec<Vec<u64>>, Vec<Op>)> {
+    let trimmed = input.trim();
+    let idx_line_ops = trimmed
+        .lines()
+        .take_while(|line| line_holds_numbers(line))
+        .count();
+
+    let numbers = trimmed.lines().take(idx_line_ops).map(|line| {
+        line.split_whitespace()
+            .map(|numstr| parse_token(input, numstr))
+            .collect()
+    });
+
+    let ops = trimmed
+        .lines()
+        .nth(idx_line_ops)
+        .ok_or_else(|| ParseError::new(input, &trimmed[trimmed.len()..], "a line of operations"))?
+        .split_whitespace()
+        .map(|op| Op::parse(input, op));
+
+    Ok((
+        numbers.collect::<ParseResult<_>>()?,
+        ops.collect::<ParseResult<_>>()?,
+    ))
+}
+
+fn line_holds_numbers(line: &str) -> bool {
+    line.split_whitespace()
+        .next()
+        .is_some_and(|num| num.parse::<u64>().is_ok())
+}
+
+common::answer_tests!(Day06);
@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+use cached::proc_macro::cached;
+use common::{
+    Result, Solution,
+    grid::{Grid, Pos},
+    parse::{ParseError, ParseResult},
+    point::Dir::{Down, Left, Right},
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(field: &Self::Input) -> impl Display {
+        let field_with_beams = process_beam_part1(field);
+        count_splittings(&field_with_beams)
+    }
+
+    fn part2(field: &Self::Input) -> impl Display {
+        let start = field.find(|cell| *cell == 'S').unwrap();
+        count_timelines(field, start, 0)
+    }
+}
+
+// Memoisation with cached gave some troubles with the lifetime of field, so a custom key is used here.
+#[cached(
+    key = "String",
+    convert = r##"{ format!("{}:{}:{}:{}", field.height(), position.y, position.x, counter) }"##
+)]
+fn count_timelines(field: &Grid<char>, position: Pos, counter: usize) -> usize {
+    let Some(below) = field.step(position, Down) else {
+        return counter + 1;
+    };
+    match field[below] {
+        '^' => [Left, Right]
+            .into_iter()
+            .map(|side| match field.step(below, side) {
+                Some(next) => count_timelines(field, next, counter),
+                // the beam leaves the manifold at its side
+                None => counter + 1,
+            })
+            .sum(),
+        _ => count_timelines(field, below, counter),
+    }
+}
+
+/// The beam enters the manifold at the `S` in the first row.
+fn parse(input: &str) -> ParseResult<Grid<char>> {
+    let field: Grid<char> = input.parse()?;
+    if field.height() == 0 || !field.row(0).contains(&'S') {
+        let first = input.lines().next().unwrap_or(input);
+        return Err(ParseError::new(input, first, "a row with the start 'S'"));
+    }
+    Ok(field)
+}
+
+pub fn process_beam_part1(field: &Grid<char>) -> Grid<char> {
+    let mut field_with_beams = field.clone();
+    for y in 1..field.height() {
+        let line = process_line(field_with_beams.row(y - 1), field.row(y));
+        field_with_beams.row_mut(y).copy_from_slice(&line);
+    }
+    field_with_beams
+}
+
+fn process_line(line_above: &[char], line_now: &[char]) -> Vec<char> {
+    line_now
+        .iter()
+        .enumerate()
+        .map(|(i, cell)| {
+            if *cell == '^' {
+                return '^';
+            }
+            let above = line_above.get(i).unwrap();
+            if *above == 'S' || *above == '|' {
+                return '|';
+            }
+            let topleft = if i > 0 { line_above.get(i - 1) } else { None };
+            let topright = line_above.get(i + 1);
+
+            match (topleft, topright) {
+                (Some('^'), _) | (_, Some('^')) => '|',
+                _ => '.',
+            }
+        })
+        .collect()
+}
+
+fn count_splittings(field_with_beams: &Grid<char>) -> usize {
+    let neighbouring_lines = field_with_beams.rows().zip(field_with_beams.rows().skip(1));
+    neighbouring_lines
+        .flat_map(|(line_prev, line_now)| {
+            line_now
+                .iter()
+                .enumerate()
+                .map(|(i, cell)| {
+                    let above = line_prev[i];
+                    match (cell, above) {
+                        ('^', '|') => 1,
+                        _ => 0,
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .sum()
+}
+
+common::answer_tests!(Day07);
@@ -0,0 +1,111 @@
+use std::fmt::Display;
+
+use common::{
+    Result, Solution,
+    disjoint_set::DisjointSet,
+    parse::{ParseResult, parse_token},
+    point::Point3,
+};
+
+pub type Coord = Point3<u64>;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Input = Vec<Coord>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(boxes: &Self::Input) -> impl Display {
+        part1(boxes, nconnections(boxes), 3)
+    }
+
+    fn part2(boxes: &Self::Input) -> impl Display {
+        part2(boxes)
+    }
+}
+
+/// The example connects its 20 boxes with 10 connections, the puzzle input uses 1000 connections.
+fn nconnections(boxes: &[Coord]) -> usize {
+    if boxes.len() <= 20 { 10 } else { 1000 }
+}
+
+fn part1(boxes: &[Coord], nconnections: usize, nlargest: usize) -> usize {
+    let combinations = sortedcombinations(boxes);
+
+    let circuits = connect_boxes(boxes, &combinations, nconnections);
+
+    // sizes of all circuits in descending order
+    let mut sizes = circuits.component_sizes();
+    sizes.sort_by_key(|size| std::cmp::Reverse(*size));
+
+    sizes.iter().take(nlargest).product()
+}
+
+/// Connects the closest pairs until all boxes form a single circuit and multiplies the
+/// X coordinates of the last pair connected.
+fn part2(boxes: &[Coord]) -> u64 {
+    let combinations = sortedcombinations(boxes);
+
+    let mut circuits: DisjointSet<Coord> = boxes.iter().copied().collect();
+    let mut ncircuits = circuits.ncomponents();
+    for (coord1, coord2) in combinations {
+        if circuits.union(coord1, coord2) {
+            ncircuits -= 1;
+            if ncircuits == 1 {
+                return coord1.x * coord2.x;
+            }
+        }
+    }
+    // a single box is a circuit already
+    0
+}
+
+fn sortedcombinations(boxes: &[Coord]) -> Vec<(Coord, Coord)> {
+    let mut combinations = vec![];
+    for i in 0..boxes.len().saturating_sub(1) {
+        for j in (i + 1)..boxes.len() {
+            combinations.push((boxes[i], boxes[j]));
+        }
+    }
+
+    // sort combinations by distances of the pairs
+    combinations.sort_by_key(|(coord1, coord2)| coord1.dist_squared(*coord2));
+
+    combinations
+}
+
+/// Circuits after connecting the closest pairs, every box not connected is a circuit on its own.
+fn connect_boxes(
+    boxes: &[Coord],
+    combinations: &[(Coord, Coord)],
+    nconnections: usize,
+) -> DisjointSet<Coord> {
+    let mut circuits: DisjointSet<Coord> = boxes.iter().copied().collect();
+    for (coord1, coord2) in combinations.iter().take(nconnections) {
+        circuits.union(*coord1, *coord2);
+    }
+    circuits
+}
+
+fn parse(input: &str) -> ParseResult<Vec<Coord>> {
+    input
+        .lines()
+        .map(|line| {
+            let mut num_iter = line.split(',');
+            let mut next = || parse_token(input, num_iter.next().unwrap_or(&line[line.len()..]));
+            Ok(Coord {
+                x: next()?,
+                y: next()?,
+                z: next()?,
+            })
+        })
+        .collect()
+}
+
+common::answer_tests!(Day08);
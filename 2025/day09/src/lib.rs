@@ -0,0 +1,105 @@
+use std::{
+    cmp::{max, min},
+    fmt::Display,
+};
+
+use common::{
+    Result, Solution,
+    parse::{ParseResult, parse_token},
+    point::Point,
+};
+
+use geo::Covers;
+use geo_types::{LineString, Polygon, Rect, coord};
+
+pub type Coord = Point<u64>;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Coord>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(redtiles: &Self::Input) -> impl Display {
+        part1(redtiles)
+    }
+
+    fn part2(redtiles: &Self::Input) -> impl Display {
+        part2(redtiles)
+    }
+}
+
+fn part1(redtiles: &[Coord]) -> u64 {
+    allrects(redtiles)
+        .iter()
+        .map(|(coord1, coord2)| rectsize(coord1, coord2))
+        .max()
+        .unwrap_or(0)
+}
+
+fn part2(redtiles: &[Coord]) -> u64 {
+    let poly: Polygon<f32> = Polygon::new(
+        LineString::from(
+            redtiles
+                .iter()
+                .map(|coord| (coord.x as f32, coord.y as f32))
+                .collect::<Vec<_>>(),
+        ),
+        vec![],
+    );
+
+    allrects(redtiles)
+        .into_iter()
+        .filter(|(coord1, coord2)| overlaps(coord1, coord2, &poly))
+        .map(|(coord1, coord2)| rectsize(&coord1, &coord2))
+        .max()
+        .unwrap_or(0)
+}
+
+fn allrects(redtiles: &[Coord]) -> Vec<(Coord, Coord)> {
+    let mut rectangles = vec![];
+    for i in 0..redtiles.len().saturating_sub(1) {
+        for j in (i + 1)..redtiles.len() {
+            rectangles.push((redtiles[i], redtiles[j]));
+        }
+    }
+    rectangles
+}
+
+/// Checks whether a point lies within or on the boundary of a polygon.
+fn overlaps(coord1: &Coord, coord2: &Coord, polygon: &Polygon<f32>) -> bool {
+    let rect = Rect::new(
+        coord! {x: coord1.x as f32, y: coord1.y as f32},
+        coord! {x: coord2.x as f32, y: coord2.y as f32},
+    );
+    polygon.covers(&rect)
+}
+
+fn rectsize(coord1: &Coord, coord2: &Coord) -> u64 {
+    let lenx = max(coord1.x, coord2.x) + 1 - min(coord1.x, coord2.x);
+    let leny = max(coord1.y, coord2.y) + 1 - min(coord1.y, coord2.y);
+    lenx * leny
+}
+
+fn parse(input: &str) -> ParseResult<Vec<Coord>> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let mut numiter = line.split(',');
+            let mut next = || parse_token(input, numiter.next().unwrap_or(&line[line.len()..]));
+            Ok(Coord {
+                x: next()?,
+                y: next()?,
+            })
+        })
+        .collect()
+}
+
+common::answer_tests!(Day09);
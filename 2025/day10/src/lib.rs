@@ -0,0 +1,321 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use common::{
+    Result, Solution,
+    parse::{ParseError, ParseResult, parse_token},
+};
+
+use rayon::prelude::*;
+
+#[derive(Debug)]
+pub struct Machine {
+    pub lights: Vec<bool>,
+    pub multibuttons: Vec<Vec<u8>>,
+    pub joltages: Vec<u32>,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(machines: &Self::Input) -> impl Display {
+        match part1(machines) {
+            Some(presses) => presses.to_string(),
+            None => "no solution".to_string(),
+        }
+    }
+
+    fn part2(machines: &Self::Input) -> impl Display {
+        match part2(machines) {
+            Some(presses) => presses.to_string(),
+            None => "no solution".to_string(),
+        }
+    }
+}
+
+fn part1(machines: &[Machine]) -> Option<usize> {
+    machines
+        .par_iter()
+        .map(|machine| fewest_presses_lights(machine).map(|buttons| buttons.len()))
+        .sum()
+}
+
+/// The buttons to press for turning on the lights of the machine, as few as possible.
+/// `None` if no combination of buttons results in the lights.
+///
+/// Pressing a button twice cancels out, so every button is pressed at most once and the lights
+/// form a linear system over GF(2): one equation per light, one variable per button.
+/// Gaussian elimination leaves free variables, each of their assignments determines the others.
+pub fn fewest_presses_lights(machine: &Machine) -> Option<Vec<usize>> {
+    let nbuttons = machine.multibuttons.len();
+    // augmented matrix, the last column holds the light
+    let mut rows: Vec<Vec<bool>> = (0..machine.lights.len())
+        .map(|light| {
+            let mut row: Vec<bool> = machine
+                .multibuttons
+                .iter()
+                .map(|button| button.contains(&(light as u8)))
+                .collect();
+            row.push(machine.lights[light]);
+            row
+        })
+        .collect();
+
+    // reduced row echelon form, the pivot of row i is in column pivots[i]
+    let mut pivots = vec![];
+    for col in 0..nbuttons {
+        let row = pivots.len();
+        let Some(pivot) = (row..rows.len()).find(|i| rows[*i][col]) else {
+            continue;
+        };
+        rows.swap(row, pivot);
+        let pivot_row = rows[row].clone();
+        for (i, other) in rows.iter_mut().enumerate() {
+            if i != row && other[col] {
+                other.iter_mut().zip(&pivot_row).for_each(|(a, b)| *a ^= b);
+            }
+        }
+        pivots.push(col);
+    }
+
+    // left over rows have no buttons, so their lights must be off
+    if rows[pivots.len()..].iter().any(|row| row[nbuttons]) {
+        return None;
+    }
+
+    let free: Vec<usize> = (0..nbuttons).filter(|col| !pivots.contains(col)).collect();
+    (0..1_u64 << free.len())
+        .map(|assignment| {
+            let mut pressed = vec![false; nbuttons];
+            for (i, col) in free.iter().enumerate() {
+                pressed[*col] = assignment >> i & 1 == 1;
+            }
+            for (row, col) in pivots.iter().enumerate() {
+                pressed[*col] = free.iter().fold(rows[row][nbuttons], |acc, f| {
+                    acc ^ (rows[row][*f] && pressed[*f])
+                });
+            }
+            pressed
+        })
+        .min_by_key(|pressed| pressed.iter().filter(|p| **p).count())
+        .map(|pressed| (0..nbuttons).filter(|button| pressed[*button]).collect())
+}
+
+fn part2(machines: &[Machine]) -> Option<u64> {
+    machines.par_iter().map(fewest_presses_joltages).sum()
+}
+
+/// Fewest presses in total which raise every counter of the machine exactly to its joltage.
+/// `None` if the joltages cannot be reached.
+///
+/// The buttons pressed an odd number of times must turn the odd joltages odd, just like the
+/// lights of part 1. Pressing each of them once leaves even joltages, and the remaining presses
+/// come in pairs: they solve the machine with the halved joltages. Every level halves the
+/// joltages, so the search ends after a few levels.
+pub fn fewest_presses_joltages(machine: &Machine) -> Option<u64> {
+    let ncounters = machine.joltages.len();
+    // joltages added by pressing each combination of buttons once, grouped by the odd counters
+    let mut combinations: HashMap<u64, Vec<(Vec<u32>, u64)>> = HashMap::new();
+    for pressed in 0..1_u64 << machine.multibuttons.len() {
+        let mut joltages = vec![0; ncounters];
+        for (i, button) in machine.multibuttons.iter().enumerate() {
+            if pressed >> i & 1 == 1 {
+                button
+                    .iter()
+                    .for_each(|counter| joltages[*counter as usize] += 1);
+            }
+        }
+        combinations
+            .entry(odd_counters(&joltages))
+            .or_default()
+            .push((joltages, pressed.count_ones().into()));
+    }
+    fewest_presses_halving(&machine.joltages, &combinations, &mut HashMap::new())
+}
+
+fn fewest_presses_halving(
+    joltages: &[u32],
+    combinations: &HashMap<u64, Vec<(Vec<u32>, u64)>>,
+    cache: &mut HashMap<Vec<u32>, Option<u64>>,
+) -> Option<u64> {
+    if joltages.iter().all(|joltage| *joltage == 0) {
+        return Some(0);
+    }
+    if let Some(presses) = cache.get(joltages) {
+        return *presses;
+    }
+    let fewest = combinations
+        .get(&odd_counters(joltages))
+        .into_iter()
+        .flatten()
+        .filter_map(|(added, presses)| {
+            let halved = joltages
+                .iter()
+                .zip(added)
+                .map(|(joltage, added)| joltage.checked_sub(*added).map(|rest| rest / 2))
+                .collect::<Option<Vec<u32>>>()?;
+            fewest_presses_halving(&halved, combinations, cache).map(|rest| presses + 2 * rest)
+        })
+        .min();
+    cache.insert(joltages.to_vec(), fewest);
+    fewest
+}
+
+fn odd_counters(joltages: &[u32]) -> u64 {
+    joltages
+        .iter()
+        .enumerate()
+        .filter(|(_i, joltage)| *joltage % 2 == 1)
+        .fold(0, |odd, (i, _joltage)| odd | 1 << i)
+}
+
+fn parse(input: &str) -> ParseResult<Vec<Machine>> {
+    input
+        .lines()
+        .map(|line| {
+            let mut parsed = Machine {
+                lights: vec![],
+                multibuttons: vec![],
+                joltages: vec![],
+            };
+            // where the joltages are, or would be
+            let mut joltages = &line[line.len()..];
+            for word in line.split_whitespace() {
+                match word.chars().next() {
+                    Some('[') => parsed.lights = parse_lights(input, word)?,
+                    Some('(') => {
+                        let button: Vec<u8> = parse_numbers(input, word, ')')?;
+                        // the lights come first, so a button cannot toggle lights which are not listed
+                        let nlights = parsed.lights.len();
+                        if button.iter().any(|light| *light as usize >= nlights) {
+                            let expected = format!("lights below {nlights}");
+                            return Err(ParseError::new(input, word, expected));
+                        }
+                        parsed.multibuttons.push(button);
+                    }
+                    Some('{') => {
+                        parsed.joltages = parse_numbers(input, word, '}')?;
+                        joltages = word;
+                    }
+                    _ => break,
+                }
+            }
+            // one counter per light
+            let nlights = parsed.lights.len();
+            if parsed.joltages.len() != nlights {
+                return Err(ParseError::new(
+                    input,
+                    joltages,
+                    format!("{nlights} joltages"),
+                ));
+            }
+            Ok(parsed)
+        })
+        .collect()
+}
+
+fn parse_lights(input: &str, s: &str) -> ParseResult<Vec<bool>> {
+    let Some(lights) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) else {
+        return Err(ParseError::new(input, s, "lights like [.##.]"));
+    };
+    lights
+        .char_indices()
+        .map(|(i, c)| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(ParseError::new(
+                input,
+                &lights[i..i + c.len_utf8()],
+                "'.' or '#'",
+            )),
+        })
+        .collect()
+}
+
+/// Parses buttons and joltages.
+fn parse_numbers<T: FromStr>(input: &str, s: &str, closing: char) -> ParseResult<Vec<T>> {
+    // remove first and last bracket
+    let Some(numbers) = s.get(1..).and_then(|s| s.strip_suffix(closing)) else {
+        return Err(ParseError::new(
+            input,
+            s,
+            format!("numbers closed by '{closing}'"),
+        ));
+    };
+    numbers
+        .split(',')
+        .map(|num| parse_token(input, num))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn machine(line: &str) -> Machine {
+        parse(line).unwrap().remove(0)
+    }
+
+    /// The lights the buttons turn on.
+    fn toggled(machine: &Machine, buttons: &[usize]) -> Vec<bool> {
+        let mut lights = vec![false; machine.lights.len()];
+        for button in buttons {
+            for light in &machine.multibuttons[*button] {
+                lights[*light as usize] ^= true;
+            }
+        }
+        lights
+    }
+
+    #[test]
+    fn presses_fewest_buttons_for_lights() {
+        let example = machine("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}");
+        let buttons = fewest_presses_lights(&example).unwrap();
+        // (0,2) with (0,1) would do as well
+        assert_eq!(buttons, [1, 3]);
+        assert_eq!(toggled(&example, &buttons), example.lights);
+        // pressing the free third button alone beats the pivots 0 and 1
+        let free = machine("[##] (0) (1) (0,1) {0,0}");
+        assert_eq!(fewest_presses_lights(&free), Some(vec![2]));
+    }
+
+    #[test]
+    fn reports_unreachable_lights() {
+        // the first light goes on with the second one
+        assert_eq!(fewest_presses_lights(&machine("[#.] (0,1) {0,0}")), None);
+        assert_eq!(fewest_presses_lights(&machine("[#.] (1) {0,0}")), None);
+    }
+
+    #[test]
+    fn rejects_missing_joltages() {
+        let err = parse("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1)").unwrap_err();
+        assert_eq!(err.to_string(), "1:39: expected 4 joltages, found nothing");
+        let err = parse("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5}").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "1:40: expected 4 joltages, found \"{3,5}\""
+        );
+    }
+
+    #[test]
+    fn reports_unreachable_joltages() {
+        // no button raises the second counter
+        assert_eq!(fewest_presses_joltages(&machine("[.#] (0) {1,2}")), None);
+        // the only button raises both counters alike
+        assert_eq!(fewest_presses_joltages(&machine("[##] (0,1) {1,2}")), None);
+        assert_eq!(
+            fewest_presses_joltages(&machine("[##] (0,1) {2,2}")),
+            Some(2)
+        );
+    }
+}
+
+common::answer_tests!(Day10);
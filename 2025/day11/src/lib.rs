@@ -0,0 +1,135 @@
+use std::{fmt::Display, hash::RandomState, path::Path};
+
+use common::{
+    Result, Solution,
+    parse::{ParseError, ParseResult, split_once},
+};
+use petgraph::{
+    algo::{all_simple_paths, dijkstra},
+    dot::{Config, Dot},
+    graph::NodeIndex,
+    prelude::StableDiGraph,
+};
+
+pub struct GraphWithIndices {
+    pub indices: Vec<String>,
+    pub graph: StableDiGraph<u32, ()>,
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 11;
+    const EXAMPLES: &'static [&'static str] = &["example", "example2"];
+
+    type Input = GraphWithIndices;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        count_paths(&input.graph, &input.indices, "you", "out")
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(&input.graph, &input.indices)
+    }
+}
+
+/// Counts all simple paths between two nodes. There is no path if one of the nodes is not part of the graph.
+fn count_paths(graph: &StableDiGraph<u32, ()>, indices: &[String], from: &str, to: &str) -> usize {
+    let idx_from = indices.iter().position(|key| key == from);
+    let idx_to = indices.iter().position(|key| key == to);
+    let (Some(idx_from), Some(idx_to)) = (idx_from, idx_to) else {
+        return 0;
+    };
+    all_simple_paths::<Vec<_>, _, RandomState>(
+        graph,
+        (idx_from as u32).into(),
+        (idx_to as u32).into(),
+        0,
+        None,
+    )
+    .count()
+}
+
+/// In contrast to part1, the big distance between "svr" and "out" causes a huge runtime.
+/// To enable fast computation, the graph is filtered to only keep nodes pointing to the according goal node.
+fn part2(graph: &StableDiGraph<u32, ()>, indices: &[String]) -> usize {
+    if ["svr", "fft", "dac", "out"]
+        .iter()
+        .any(|node| !indices.iter().any(|key| key == node))
+    {
+        return 0;
+    }
+    let to_fft = trim_after_node(graph, indices, "fft");
+    let to_dac = trim_after_node(graph, indices, "dac");
+    let to_out = trim_after_node(graph, indices, "out");
+    count_paths(&to_fft, indices, "svr", "fft")
+        * count_paths(&to_dac, indices, "fft", "dac")
+        * count_paths(&to_out, indices, "dac", "out")
+}
+
+/// Removes every node, which has no reachable path to "node".
+fn trim_after_node(
+    graph: &StableDiGraph<u32, ()>,
+    indices: &[String],
+    node: &str,
+) -> StableDiGraph<u32, ()> {
+    let idx_node = indices.iter().position(|key| *key == node).unwrap() as u32;
+    let mut newgraph = graph.clone();
+    let followingnodes = graph
+        .node_indices()
+        .map(|i| dijkstra(&graph, i, None, |_| 1))
+        .filter(|nodes| {
+            let key = NodeIndex::new(idx_node as usize);
+            nodes.contains_key(&key) && *nodes.get(&key).unwrap() == 0
+        });
+    for node_dist in followingnodes {
+        for (node, dist) in node_dist {
+            if dist != 0 {
+                // dist == 0: the goal node, which is included in this list as it is reachable with a distance of 0
+                newgraph.remove_node(node);
+            }
+        }
+    }
+    newgraph
+}
+
+fn parse(input: &str) -> ParseResult<GraphWithIndices> {
+    let lines = input
+        .lines()
+        .map(|line| split_once(input, line, ":"))
+        .collect::<ParseResult<Vec<_>>>()?;
+    let mut indices: Vec<String> = lines.iter().map(|(from, _)| from.to_string()).collect();
+
+    // There is a single node without outgoing edges
+    indices.push("out".to_string());
+
+    // egdes is a tuple of directed edges from first to second element
+    let mut edges = vec![];
+    for (idxfrom, (_from, to)) in lines.iter().enumerate() {
+        for edge in to.split_whitespace() {
+            let idxto = indices
+                .iter()
+                .position(|key| key == edge)
+                .ok_or_else(|| ParseError::new(input, edge, "a listed device or 'out'"))?;
+            edges.push((idxfrom as u32, idxto as u32));
+        }
+    }
+
+    let graph = StableDiGraph::<u32, ()>::from_edges(edges);
+    Ok(GraphWithIndices { indices, graph })
+}
+
+/// Writes the graph next to the input file, e.g. `input/example.dot`.
+pub fn save_dot(input: &GraphWithIndices, path: &Path) {
+    let dot = Dot::with_config(&input.graph, &[Config::NodeIndexLabel]);
+    let mut name = path.as_os_str().to_owned();
+    name.push(".dot");
+    std::fs::write(name, format!("{:?}", dot)).unwrap();
+}
+
+common::answer_tests!(Day11);
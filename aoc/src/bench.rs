@@ -0,0 +1,81 @@
+use common::{
+    bench::{Phase, Stats},
+    input::Source,
+};
+use serde::Serialize;
+
+use crate::{Selection, days::Day};
+
+#[derive(Serialize)]
+struct Measurement {
+    year: u16,
+    day: u8,
+    phase: Phase,
+    runs: u32,
+    min_ns: u128,
+    median_ns: u128,
+    max_ns: u128,
+}
+
+/// Runs each day `runs` times and reports the spread of every phase as a table or as JSON.
+/// Returns whether all days could be measured.
+pub fn bench(selection: &Selection, days: &[&Day], runs: u32, json: bool) -> bool {
+    let mut measured = true;
+    let mut measurements = vec![];
+    if !json {
+        println!(
+            "{:<12} {:<6} {:>10} {:>10} {:>10}",
+            "day", "phase", "min", "median", "max"
+        );
+    }
+    for day in days {
+        let source = selection.source(day);
+        let stats = match measure(day, &source, runs) {
+            Ok(stats) => stats,
+            Err(err) => {
+                eprintln!("{} day {:02} ({source}) - {err}", day.year, day.day);
+                measured = false;
+                continue;
+            }
+        };
+        for (phase, stats) in Phase::ALL.into_iter().zip(stats) {
+            if json {
+                measurements.push(Measurement {
+                    year: day.year,
+                    day: day.day,
+                    phase,
+                    runs,
+                    min_ns: stats.min.as_nanos(),
+                    median_ns: stats.median.as_nanos(),
+                    max_ns: stats.max.as_nanos(),
+                });
+            } else {
+                println!(
+                    "{:<12} {:<6} {:>10} {:>10} {:>10}",
+                    format!("{} day {:02}", day.year, day.day),
+                    phase.to_string(),
+                    format!("{:.2?}", stats.min),
+                    format!("{:.2?}", stats.median),
+                    format!("{:.2?}", stats.max),
+                );
+            }
+        }
+    }
+    if json {
+        let json = serde_json::to_string_pretty(&measurements).expect("plain numbers and strings");
+        println!("{json}");
+    }
+    measured
+}
+
+/// Reads the input once and times all runs on it.
+fn measure(day: &Day, source: &Source, runs: u32) -> common::Result<[Stats; 3]> {
+    let input = source.read()?;
+    let mut durations = [vec![], vec![], vec![]];
+    for _ in 0..runs {
+        for (phase, duration) in durations.iter_mut().zip((day.time)(&input)?) {
+            phase.push(duration);
+        }
+    }
+    Ok(durations.map(|durations| Stats::of(&durations)))
+}
@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use common::{Answers, Result, Solution, input::Source};
+
+/// A single puzzle solution, callable on an input source.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solve: fn(&Source) -> Result<Answers>,
+    /// Times the phases of a single run on the input.
+    pub time: fn(&str) -> Result<[Duration; 3]>,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Day {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            solve: common::solve_source::<S>,
+            time: common::bench::time::<S>,
+        }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::of::<aoc2024_day01::Day01>(),
+    Day::of::<aoc2024_day02::Day02>(),
+    Day::of::<aoc2024_day03::Day03>(),
+    Day::of::<aoc2024_day04::Day04>(),
+    Day::of::<aoc2024_day05::Day05>(),
+    Day::of::<aoc2024_day06::Day06>(),
+    Day::of::<aoc2024_day07::Day07>(),
+    Day::of::<aoc2024_day08::Day08>(),
+    Day::of::<aoc2024_day09::Day09>(),
+    Day::of::<aoc2024_day10::Day10>(),
+    Day::of::<aoc2024_day11::Day11>(),
+    Day::of::<aoc2025_day01::Day01>(),
+    Day::of::<aoc2025_day02::Day02>(),
+    Day::of::<aoc2025_day03::Day03>(),
+    Day::of::<aoc2025_day04::Day04>(),
+    Day::of::<aoc2025_day05::Day05>(),
+    Day::of::<aoc2025_day06::Day06>(),
+    Day::of::<aoc2025_day07::Day07>(),
+    Day::of::<aoc2025_day08::Day08>(),
+    Day::of::<aoc2025_day09::Day09>(),
+    Day::of::<aoc2025_day10::Day10>(),
+    Day::of::<aoc2025_day11::Day11>(),
+];
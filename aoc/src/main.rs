@@ -0,0 +1,107 @@
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+use common::input::Source;
+
+use days::{DAYS, Day};
+
+mod bench;
+mod days;
+
+/// Runs the Advent of Code solutions of this repository.
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solution of a single day or of all days of a year.
+    Run(Selection),
+    /// Times parsing and both parts of a single day or of all days of a year.
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        /// How often each day is run.
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Prints the measurements as JSON instead of a table.
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// The days to work on and their input.
+#[derive(Args)]
+struct Selection {
+    year: u16,
+    /// Day of the puzzle, required unless --all is given.
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Selects every day of the year.
+    #[arg(long)]
+    all: bool,
+    /// Input file or `-` for stdin. Defaults to the located puzzle input of the day.
+    #[arg(long, conflicts_with = "all")]
+    input: Option<String>,
+}
+
+impl Selection {
+    fn days(&self) -> Vec<&'static Day> {
+        DAYS.iter()
+            .filter(|d| d.year == self.year && self.day.is_none_or(|day| d.day == day))
+            .collect()
+    }
+
+    fn source(&self, day: &Day) -> Source {
+        match &self.input {
+            Some(arg) => Source::from_arg(arg),
+            None => Source::Puzzle {
+                year: day.year,
+                day: day.day,
+            },
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let (Command::Run(selection) | Command::Bench { selection, .. }) = &cli.command;
+    let days = selection.days();
+    if days.is_empty() {
+        eprintln!(
+            "no solution for {} {}",
+            selection.year,
+            selection.day.map_or("".into(), |d| d.to_string())
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let succeeded = match cli.command {
+        Command::Run(_) => run(selection, &days),
+        Command::Bench { runs, json, .. } => bench::bench(selection, &days, runs, json),
+    };
+    if succeeded {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Prints the answers of every day, returns whether all of them could be solved.
+fn run(selection: &Selection, days: &[&Day]) -> bool {
+    let mut failed = false;
+    for day in days {
+        let source = selection.source(day);
+        println!("--- {} day {:02} ({source}) ---", day.year, day.day);
+        match (day.solve)(&source) {
+            Ok(answers) => println!("{answers}"),
+            Err(err) => {
+                eprintln!("{err}");
+                failed = true;
+            }
+        }
+    }
+    !failed
+}
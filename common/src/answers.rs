@@ -0,0 +1,134 @@
+//! Regression tests against the expected answers listed in the `answers.toml` of each day:
+//!
+//! ```toml
+//! [example]
+//! part1 = 11
+//! part2 = 31
+//!
+//! [input]
+//! part1 = 1234
+//! ```
+//!
+//! Every table names an input within the crate of the day, except for `[input]`, the puzzle input
+//! as located by [`input::locate`](crate::input::locate).
+//! A puzzle input that is neither present nor cached is skipped, any other missing input fails.
+use std::{collections::BTreeMap, fmt::Display, path::Path};
+
+use serde::Deserialize;
+use toml::Value;
+
+use crate::{
+    Solution,
+    input::{PUZZLE_INPUT, Source, in_crate},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "part1"),
+            Part::Two => write!(f, "part2"),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Expected {
+    part1: Option<Value>,
+    part2: Option<Value>,
+}
+
+impl Expected {
+    fn get(&self, part: Part) -> Option<String> {
+        let value = match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }?;
+        Some(match value {
+            Value::String(answer) => answer.clone(),
+            answer => answer.to_string(),
+        })
+    }
+}
+
+/// Generates the tests `part1` and `part2`, which check every answer in the `answers.toml` next to
+/// the Cargo.toml of the calling crate.
+#[macro_export]
+macro_rules! answer_tests {
+    ($day:ident) => {
+        #[cfg(test)]
+        mod answers {
+            use $crate::answers::{Part, check};
+
+            #[test]
+            fn part1() {
+                check::<super::$day>(env!("CARGO_MANIFEST_DIR"), Part::One);
+            }
+
+            #[test]
+            fn part2() {
+                check::<super::$day>(env!("CARGO_MANIFEST_DIR"), Part::Two);
+            }
+        }
+    };
+}
+
+/// Panics with a list of all mismatches between the expected and the computed answers.
+pub fn check<S: Solution>(crate_dir: &str, part: Part) {
+    let path = Path::new(crate_dir).join("answers.toml");
+    let content = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("cannot read {}: {err}", path.display()));
+    let expectations: BTreeMap<String, Expected> = toml::from_str(&content)
+        .unwrap_or_else(|err| panic!("cannot parse {}: {err}", path.display()));
+
+    let mut mismatches = vec![];
+    for (name, expected) in &expectations {
+        let Some(expected) = expected.get(part) else {
+            continue;
+        };
+        let source = match name.as_str() {
+            PUZZLE_INPUT => Source::Puzzle {
+                year: S::YEAR,
+                day: S::DAY,
+            },
+            // the examples belong to the crate, unlike puzzle inputs kept elsewhere
+            name => Source::File(in_crate(Path::new(crate_dir), S::YEAR, name)),
+        };
+        let input = match (source.read(), &source) {
+            (Ok(input), _) => input,
+            // puzzle inputs may not be distributed
+            (Err(_), Source::Puzzle { .. }) => {
+                eprintln!("skipping {name}: {source} is not available");
+                continue;
+            }
+            (Err(err), _) => {
+                mismatches.push(format!("{name}: {source} - {err}"));
+                continue;
+            }
+        };
+
+        let answer = match S::parse(&input) {
+            Ok(parsed) => match part {
+                Part::One => S::part1(&parsed).to_string(),
+                Part::Two => S::part2(&parsed).to_string(),
+            },
+            Err(err) => format!("parse error: {err}"),
+        };
+        if answer != expected {
+            mismatches.push(format!("{name}: expected {expected}, got {answer}"));
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "{} day {} {part}:\n{}",
+        S::YEAR,
+        S::DAY,
+        mismatches.join("\n")
+    );
+}
@@ -0,0 +1,89 @@
+//! Timing the phases of a solution, to track regressions while optimising slow days.
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{Result, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part1"),
+            Phase::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+/// Durations of the phases of a single run, in the order of [`Phase::ALL`].
+/// The parts include formatting their answers, as some compute them lazily.
+pub fn time<S: Solution>(input: &str) -> Result<[Duration; 3]> {
+    let start = Instant::now();
+    let parsed = S::parse(black_box(input))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part1(&parsed).to_string());
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part2(&parsed).to_string());
+    let part2 = start.elapsed();
+
+    Ok([parse, part1, part2])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Panics without any durations.
+    pub fn of(durations: &[Duration]) -> Stats {
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = match sorted.len() % 2 {
+            0 => (sorted[mid - 1] + sorted[mid]) / 2,
+            _ => sorted[mid],
+        };
+        Stats {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_stats() {
+        let ms = Duration::from_millis;
+        let odd = Stats::of(&[ms(5), ms(1), ms(3)]);
+        assert_eq!((odd.min, odd.median, odd.max), (ms(1), ms(3), ms(5)));
+        let even = Stats::of(&[ms(4), ms(1), ms(2), ms(9)]);
+        assert_eq!(even.median, ms(3));
+    }
+}
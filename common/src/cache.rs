@@ -0,0 +1,214 @@
+//! Local cache of puzzle inputs (`~/.cache/aoc/2025/09.txt`), filled by downloading them
+//! from adventofcode.com with the session cookie of a logged in user.
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Advent of Code asks automated requests to identify where they come from.
+const USER_AGENT: &str = "github.com/lsiefke/advent-of-code";
+
+/// The single request the fetcher needs, so tests can replace the network.
+pub trait Http {
+    fn get(&self, url: &str, cookie: &str) -> Result<String>;
+}
+
+pub struct Ureq;
+
+impl Http for Ureq {
+    fn get(&self, url: &str, cookie: &str) -> Result<String> {
+        let mut response = ureq::get(url)
+            .header("Cookie", cookie)
+            .header("User-Agent", USER_AGENT)
+            .call()?;
+        Ok(response.body_mut().read_to_string()?)
+    }
+}
+
+pub struct Fetcher<H: Http> {
+    http: H,
+    base_url: String,
+    session: String,
+}
+
+impl<H: Http> Fetcher<H> {
+    pub fn new(http: H, base_url: &str, session: &str) -> Fetcher<H> {
+        Fetcher {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let cookie = format!("session={}", self.session);
+        self.http
+            .get(&url, &cookie)
+            .map_err(|err| format!("cannot download {url}: {err}").into())
+    }
+}
+
+impl Fetcher<Ureq> {
+    /// Fetches from adventofcode.com if a session token is set in `AOC_SESSION`.
+    pub fn from_env() -> Option<Fetcher<Ureq>> {
+        let session = std::env::var(SESSION_VAR).ok()?;
+        Some(Fetcher::new(Ureq, BASE_URL, &session))
+    }
+}
+
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: &Path) -> Cache {
+        Cache {
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    /// `$AOC_CACHE_DIR`, `$XDG_CACHE_HOME/aoc` or `~/.cache/aoc`, whichever is set first.
+    pub fn from_env() -> Option<Cache> {
+        let var = |name| std::env::var_os(name).map(PathBuf::from);
+        let dir = var(CACHE_DIR_VAR)
+            .or_else(|| var("XDG_CACHE_HOME").map(|dir| dir.join("aoc")))
+            .or_else(|| var("HOME").map(|dir| dir.join(".cache").join("aoc")))?;
+        Some(Cache { dir })
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("{day:02}.txt"))
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<String> {
+        std::fs::read_to_string(self.path(year, day)).ok()
+    }
+
+    pub fn store(&self, year: u16, day: u8, input: &str) -> Result<()> {
+        let path = self.path(year, day);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&path, input)?;
+        Ok(())
+    }
+
+    /// Returns the cached input or downloads and caches it.
+    pub fn get_or_fetch<H: Http>(
+        &self,
+        year: u16,
+        day: u8,
+        fetcher: Option<&Fetcher<H>>,
+    ) -> Result<String> {
+        if let Some(input) = self.get(year, day) {
+            return Ok(input);
+        }
+        let Some(fetcher) = fetcher else {
+            return Err(format!(
+                "{} is not cached and {SESSION_VAR} is not set to download it",
+                self.path(year, day).display()
+            )
+            .into());
+        };
+        let input = fetcher.input(year, day)?;
+        self.store(year, day, &input)?;
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::*;
+
+    /// Answers a single request with the given body and hands back the request head.
+    fn stub_server(body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let head: Vec<String> = BufReader::new(&stream)
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            head
+        });
+        (url, handle)
+    }
+
+    struct CountingHttp {
+        calls: Cell<usize>,
+    }
+
+    impl Http for CountingHttp {
+        fn get(&self, _url: &str, _cookie: &str) -> Result<String> {
+            self.calls.set(self.calls.get() + 1);
+            Ok("1 2 3\n".to_string())
+        }
+    }
+
+    fn temp_cache(name: &str) -> Cache {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        Cache::new(&dir)
+    }
+
+    #[test]
+    fn fetches_with_session_cookie() {
+        let (url, server) = stub_server("125 17\n");
+        let fetcher = Fetcher::new(Ureq, &url, "secret\n");
+
+        assert_eq!(fetcher.input(2024, 11).unwrap(), "125 17\n");
+        let head = server.join().unwrap();
+        assert_eq!(head[0], "GET /2024/day/11/input HTTP/1.1");
+        assert!(
+            head.iter()
+                .any(|line| line.eq_ignore_ascii_case("cookie: session=secret"))
+        );
+    }
+
+    #[test]
+    fn downloads_only_once() {
+        let cache = temp_cache("once");
+        let fetcher = Fetcher::new(
+            CountingHttp {
+                calls: Cell::new(0),
+            },
+            BASE_URL,
+            "secret",
+        );
+
+        for _ in 0..2 {
+            let input = cache.get_or_fetch(2025, 9, Some(&fetcher)).unwrap();
+            assert_eq!(input, "1 2 3\n");
+        }
+        assert_eq!(fetcher.http.calls.get(), 1);
+        assert!(cache.path(2025, 9).ends_with("2025/09.txt"));
+    }
+
+    #[test]
+    fn fails_without_session() {
+        let cache = temp_cache("nosession");
+        let err = cache.get_or_fetch::<Ureq>(2025, 1, None).unwrap_err();
+        assert!(err.to_string().contains(SESSION_VAR));
+    }
+}
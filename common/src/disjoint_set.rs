@@ -0,0 +1,210 @@
+//! Union-find over arbitrary keys, for puzzles grouping things into connected components.
+//!
+//! Keys are numbered in the order they are first seen, the sets are kept as a forest over these
+//! numbers with path compression and union by rank.
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+#[derive(Debug, Clone)]
+pub struct DisjointSet<K> {
+    indices: HashMap<K, usize>,
+    keys: Vec<K>,
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    /// Number of keys in the component, only valid for the root of a component.
+    size: Vec<usize>,
+}
+
+impl<K: Hash + Eq + Clone> DisjointSet<K> {
+    pub fn new() -> DisjointSet<K> {
+        DisjointSet {
+            indices: HashMap::new(),
+            keys: vec![],
+            parent: vec![],
+            rank: vec![],
+            size: vec![],
+        }
+    }
+
+    /// Adds the key as a component on its own, returns whether it was not known before.
+    pub fn insert(&mut self, key: K) -> bool {
+        if self.indices.contains_key(&key) {
+            return false;
+        }
+        self.index(key);
+        true
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    /// Number of keys in all components.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Number of components.
+    pub fn ncomponents(&self) -> usize {
+        (0..self.parent.len())
+            .filter(|i| self.parent[*i] == *i)
+            .count()
+    }
+
+    /// The key representing the component of the key, `None` for unknown keys.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let i = *self.indices.get(key)?;
+        let root = self.find_index(i);
+        Some(&self.keys[root])
+    }
+
+    /// Whether both keys are known and in the same component.
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.indices.get(a), self.indices.get(b)) {
+            (Some(a), Some(b)) => {
+                let (a, b) = (*a, *b);
+                self.find_index(a) == self.find_index(b)
+            }
+            _ => false,
+        }
+    }
+
+    /// Merges the components of both keys, unknown keys are inserted first.
+    /// Returns whether they were separate components before.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.index(a), self.index(b));
+        let (mut a, mut b) = (self.find_index(a), self.find_index(b));
+        if a == b {
+            return false;
+        }
+        if self.rank[a] < self.rank[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        if self.rank[a] == self.rank[b] {
+            self.rank[a] += 1;
+        }
+        true
+    }
+
+    /// Number of keys in each component, in the order of [`DisjointSet::components`].
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let mut seen = HashSet::new();
+        (0..self.keys.len())
+            .map(|i| self.root(i))
+            .filter(|root| seen.insert(*root))
+            .map(|root| self.size[root])
+            .collect()
+    }
+
+    /// Keys of each component, components ordered by the first key seen of them and keys in the
+    /// order they were seen.
+    pub fn components(&self) -> impl Iterator<Item = Vec<&K>> {
+        let mut components: Vec<Vec<&K>> = vec![];
+        let mut numbers = HashMap::new();
+        for (i, key) in self.keys.iter().enumerate() {
+            let number = *numbers.entry(self.root(i)).or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+            components[number].push(key);
+        }
+        components.into_iter()
+    }
+
+    /// Number of the component of every key, as ordered by [`DisjointSet::components`].
+    /// Unlike [`DisjointSet::find`], the numbers stay valid while the sets change.
+    pub fn membership(&self) -> HashMap<K, usize> {
+        self.components()
+            .enumerate()
+            .flat_map(|(number, keys)| keys.into_iter().map(move |key| (key.clone(), number)))
+            .collect()
+    }
+
+    fn index(&mut self, key: K) -> usize {
+        if let Some(i) = self.indices.get(&key) {
+            return *i;
+        }
+        let i = self.keys.len();
+        self.indices.insert(key.clone(), i);
+        self.keys.push(key);
+        self.parent.push(i);
+        self.rank.push(0);
+        self.size.push(1);
+        i
+    }
+
+    /// Root of the index, pointing the whole path to it.
+    fn find_index(&mut self, i: usize) -> usize {
+        let root = self.root(i);
+        let mut i = i;
+        while self.parent[i] != root {
+            i = std::mem::replace(&mut self.parent[i], root);
+        }
+        root
+    }
+
+    /// Root of the index, without compressing the path.
+    fn root(&self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            i = self.parent[i];
+        }
+        i
+    }
+}
+
+impl<K: Hash + Eq + Clone> Default for DisjointSet<K> {
+    fn default() -> DisjointSet<K> {
+        DisjointSet::new()
+    }
+}
+
+/// Every key becomes a component on its own.
+impl<K: Hash + Eq + Clone> FromIterator<K> for DisjointSet<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(keys: I) -> DisjointSet<K> {
+        let mut set = DisjointSet::new();
+        keys.into_iter().for_each(|key| {
+            set.insert(key);
+        });
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_components() {
+        let mut set: DisjointSet<char> = "abcdef".chars().collect();
+        assert!(set.union('a', 'c'));
+        assert!(set.union('d', 'e'));
+        assert!(set.union('e', 'a'));
+        assert!(!set.union('c', 'd'));
+        assert!(set.union('g', 'f'));
+
+        assert!(set.connected(&'a', &'d'));
+        assert!(!set.connected(&'a', &'b'));
+        assert_eq!(set.find(&'e').copied(), set.find(&'c').copied());
+        assert_eq!(set.find(&'x'), None);
+        assert_eq!(set.len(), 7);
+        assert_eq!(set.ncomponents(), 3);
+        assert_eq!(set.component_sizes(), [4, 1, 2]);
+        assert_eq!(
+            set.components().collect::<Vec<_>>(),
+            [vec![&'a', &'c', &'d', &'e'], vec![&'b'], vec![&'f', &'g']]
+        );
+        let membership = set.membership();
+        assert_eq!(
+            (membership[&'c'], membership[&'b'], membership[&'g']),
+            (0, 1, 2)
+        );
+    }
+}
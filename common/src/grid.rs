@@ -0,0 +1,283 @@
+//! Dense two-dimensional maps, which many puzzles use as input.
+//!
+//! Cells are stored row by row. The `x` of a position is the column and `y` the row counted
+//! from the top left corner.
+use std::{
+    any::type_name,
+    fmt::Display,
+    iter::successors,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{
+    parse::{ParseError, ParseResult},
+    point::{
+        Dir::{self, Down, Left, Right, Up},
+        Point,
+    },
+};
+
+pub type Pos = Point<usize>;
+
+/// Neighbours sharing a corner, clockwise from the top left.
+const CORNERS: [[Dir; 2]; 4] = [[Up, Left], [Up, Right], [Down, Right], [Down, Left]];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cell: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    /// Parses one cell per character, `expected` describes the valid characters.
+    /// All rows must be of the same length, trailing line breaks are ignored.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> ParseResult<Grid<T>> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for line in input.trim_end_matches(['\r', '\n']).lines() {
+            let len = line.chars().count();
+            match width {
+                Some(width) if width != len => {
+                    return Err(ParseError::new(
+                        input,
+                        line,
+                        format!("a row of {width} cells"),
+                    ));
+                }
+                _ => width = Some(len),
+            }
+            for (i, c) in line.char_indices() {
+                let token = &line[i..i + c.len_utf8()];
+                cells.push(cell(c).ok_or_else(|| ParseError::new(input, token, expected))?);
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y * self.width + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.y * self.width + pos.x])
+    }
+
+    /// The neighbouring position in the direction, `None` at the edge of the grid.
+    pub fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        pos.step(dir).filter(|next| self.contains(*next))
+    }
+
+    /// All positions row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, which satisfies the predicate.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_pos, cell)| predicate(cell))
+            .map(|(pos, _cell)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).map(move |y| &self[Point::new(x, y)])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Diagonals from the top left to the bottom right, starting with the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (1..self.height)
+            .rev()
+            .map(|y| Point::new(0, y))
+            .chain((0..self.width).map(|x| Point::new(x, 0)));
+        starts.map(|start| self.line(start, [Down, Right]))
+    }
+
+    /// Diagonals from the top right to the bottom left, starting with the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let right = self.width.saturating_sub(1);
+        let starts = (0..self.width)
+            .map(|x| Point::new(x, 0))
+            .chain((1..self.height).map(move |y| Point::new(right, y)));
+        starts.map(|start| self.line(start, [Down, Left]))
+    }
+
+    /// Neighbours sharing an edge with the cell, clockwise starting above it.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Neighbours sharing an edge or a corner with the cell, the ones sharing an edge first.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        let corners = CORNERS
+            .into_iter()
+            .filter_map(move |dirs| self.walk(pos, dirs));
+        self.neighbours4(pos).chain(corners)
+    }
+
+    /// Takes one step into each of the directions.
+    fn walk(&self, pos: Pos, dirs: [Dir; 2]) -> Option<Pos> {
+        dirs.into_iter()
+            .try_fold(pos, |pos, dir| self.step(pos, dir))
+    }
+
+    /// Cells from `start` in steps of the directions until the edge is reached.
+    fn line(&self, start: Pos, dirs: [Dir; 2]) -> impl Iterator<Item = &T> {
+        successors(self.get(start).map(|_| start), move |pos| {
+            self.walk(*pos, dirs)
+        })
+        .map(|pos| &self[pos])
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside of {}x{}", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside of {width}x{height}"))
+    }
+}
+
+/// Parses any cell type which can be built from a character, e.g. `Grid<char>`.
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Grid<T>> {
+        let expected = type_name::<T>().rsplit("::").next().unwrap_or("a cell");
+        Grid::parse(s, expected, |c| T::try_from(c).ok())
+    }
+}
+
+/// Prints the rows on separate lines, without a line break after the last one.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn iterates_lines() {
+        let grid: Grid<char> = "abc\ndef\n".parse().unwrap();
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(letters(grid.columns()), ["ad", "be", "cf"]);
+        assert_eq!(letters(grid.diagonals()), ["d", "ae", "bf", "c"]);
+        assert_eq!(letters(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&0));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.step(Point::new(2, 1), Right), None);
+        let neighbours = grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(neighbours, [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Point::new(2, 1)).count(), 3);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let err = "ab\nc\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "2:1: expected a row of 2 cells, found \"c\""
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! Locating the input files of a day.
+//!
+//! An input is either given explicitly (a path, or `-` for stdin) or looked up by its name,
+//! e.g. `example` or `input`:
+//! 1. `$AOC_INPUT_DIR/<year>/dayNN/<name>` if the environment variable is set,
+//! 2. the default layout of the year within this repository.
+//!
+//! The puzzle input itself falls back to the [`cache`](crate::cache), which downloads it if needed.
+use std::{
+    fmt::Display,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    Result, Solution,
+    cache::{Cache, Fetcher},
+};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Name of the puzzle input, in contrast to the examples.
+pub const PUZZLE_INPUT: &str = "input";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+    /// The located puzzle input of a day, or the cached one if there is no such file.
+    Puzzle {
+        year: u16,
+        day: u8,
+    },
+}
+
+impl Source {
+    /// Interprets a command line argument: `-` is stdin, anything else a path.
+    pub fn from_arg(arg: &str) -> Source {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::File(path) => {
+                std::fs::read_to_string(path).map_err(|err| format!("cannot read: {err}").into())
+            }
+            Source::Puzzle { year, day } => match self.local_path() {
+                Some(path) => Source::File(path).read(),
+                None => Cache::from_env()
+                    .ok_or("no cache directory, set HOME or AOC_CACHE_DIR")?
+                    .get_or_fetch(*year, *day, Fetcher::from_env().as_ref()),
+            },
+        }
+    }
+
+    /// The file the input is read from, unless it comes from stdin or the cache.
+    pub fn local_path(&self) -> Option<PathBuf> {
+        match self {
+            Source::Stdin => None,
+            Source::File(path) => Some(path.clone()),
+            Source::Puzzle { year, day } => {
+                Some(locate(*year, *day, PUZZLE_INPUT)).filter(|path| path.is_file())
+            }
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Puzzle { year, day } => match self.local_path() {
+                Some(path) => write!(f, "{}", path.display()),
+                None => write!(f, "cached input of {year} day {day:02}"),
+            },
+        }
+    }
+}
+
+/// Path of the input called `name` of the given day.
+pub fn locate(year: u16, day: u8, name: &str) -> PathBuf {
+    match std::env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => day_dir(Path::new(&dir), year, day).join(name),
+        None => default_dir(year, day).join(name),
+    }
+}
+
+/// The sources a day's binary works on: the first command line argument if given,
+/// otherwise all examples followed by the puzzle input.
+pub fn sources<S: Solution>() -> Vec<Source> {
+    match std::env::args().nth(1) {
+        Some(arg) => vec![Source::from_arg(&arg)],
+        None => S::EXAMPLES
+            .iter()
+            .map(|name| Source::File(locate(S::YEAR, S::DAY, name)))
+            .chain([Source::Puzzle {
+                year: S::YEAR,
+                day: S::DAY,
+            }])
+            .collect(),
+    }
+}
+
+/// Path of the input called `name` within the crate of a day, regardless of [`INPUT_DIR_VAR`].
+pub fn in_crate(crate_dir: &Path, year: u16, name: &str) -> PathBuf {
+    inputs_of_crate(crate_dir, year).join(name)
+}
+
+fn default_dir(year: u16, day: u8) -> PathBuf {
+    inputs_of_crate(&day_dir(&workspace_root(), year, day), year)
+}
+
+/// 2024 keeps the inputs next to the Cargo.toml of each day, later years within an `input` directory.
+fn inputs_of_crate(crate_dir: &Path, year: u16) -> PathBuf {
+    match year {
+        2024 => crate_dir.to_path_buf(),
+        _ => crate_dir.join("input"),
+    }
+}
+
+fn day_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day{day:02}"))
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("common is a member of the workspace")
+        .to_path_buf()
+}
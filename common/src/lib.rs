@@ -0,0 +1,105 @@
+use std::{fmt::Display, process::ExitCode};
+
+use input::Source;
+use parse::ParseError;
+
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod disjoint_set;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod point;
+pub mod visual;
+
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Shape shared by the solutions of all days: the input is parsed once and then handed to both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// Names of the example inputs, which are solved before the puzzle input.
+    const EXAMPLES: &'static [&'static str] = &["example"];
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> impl Display;
+
+    /// Defaults to a placeholder for puzzles whose second part is not solved yet.
+    fn part2(_input: &Self::Input) -> impl Display {
+        "unsolved"
+    }
+}
+
+/// Answers of both parts, rendered to strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "part1: {}, part2: {}", self.part1, self.part2)
+    }
+}
+
+/// Parses the input and solves both parts.
+pub fn solve<S: Solution>(input: &str) -> Result<Answers> {
+    Ok(answers::<S>(&S::parse(input)?))
+}
+
+/// Solves both parts of the parsed input.
+pub fn answers<S: Solution>(input: &S::Input) -> Answers {
+    Answers {
+        part1: S::part1(input).to_string(),
+        part2: S::part2(input).to_string(),
+    }
+}
+
+/// Entry point of the binary of a day, see [`input::sources`] for the inputs it solves.
+pub fn main<S: Solution>() -> ExitCode {
+    main_with::<S>(|_source, input| Ok(answers::<S>(input)))
+}
+
+/// Like [`main`], but `solve` gets each parsed input to produce the answers, e.g. to show or export
+/// something besides them. Fails if any source cannot be read, parsed or solved.
+pub fn main_with<S: Solution>(
+    mut solve: impl FnMut(&Source, &S::Input) -> Result<Answers>,
+) -> ExitCode {
+    let mut failed = false;
+    for source in input::sources::<S>() {
+        match parse_source::<S>(&source).and_then(|input| solve(&source, &input)) {
+            Ok(answers) => println!("{source} - {answers}"),
+            Err(err) => {
+                match err.downcast_ref::<ParseError>() {
+                    // already names the file
+                    Some(ParseError { file: Some(_), .. }) => eprintln!("{err}"),
+                    _ => eprintln!("{source} - {err}"),
+                }
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+pub fn solve_source<S: Solution>(source: &Source) -> Result<Answers> {
+    Ok(answers::<S>(&parse_source::<S>(source)?))
+}
+
+/// Reads and parses the source, parse errors name the file it was read from.
+pub fn parse_source<S: Solution>(source: &Source) -> Result<S::Input> {
+    S::parse(&source.read()?).map_err(|err| match source.local_path() {
+        Some(path) => parse::with_file(err, &path),
+        None => err,
+    })
+}
@@ -0,0 +1,124 @@
+//! Errors pointing at the offending token of an input, e.g. `input:3:7: expected u32, found "12\r"`.
+//!
+//! Tokens are slices of the input, so their line and column are derived from their address.
+use std::{
+    any::type_name,
+    fmt::Display,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::Error;
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    /// Starts at 1, 0 if the token is not a slice of the input.
+    pub line: usize,
+    /// Starts at 1 and counts characters, 0 if the token is not a slice of the input.
+    pub column: usize,
+    pub token: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(input: &str, token: &str, expected: impl Into<String>) -> ParseError {
+        let (line, column) = position(input, token).unwrap_or((0, 0));
+        ParseError {
+            file: None,
+            line,
+            column,
+            token: token.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn with_file(self, file: &Path) -> ParseError {
+        ParseError {
+            file: Some(file.to_path_buf()),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        if self.line > 0 {
+            write!(f, "{}:{}: ", self.line, self.column)?;
+        }
+        match self.token.as_str() {
+            "" => write!(f, "expected {}, found nothing", self.expected),
+            token => write!(f, "expected {}, found {token:?}", self.expected),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Line and column of `token` within `input`, both starting at 1.
+fn position(input: &str, token: &str) -> Option<(usize, usize)> {
+    let offset = (token.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+    let before = input.get(..offset)?;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Some((
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    ))
+}
+
+/// Parses a token of the input, e.g. a number.
+pub fn parse_token<T: FromStr>(input: &str, token: &str) -> ParseResult<T> {
+    token.parse().map_err(|_| {
+        let expected = type_name::<T>().rsplit("::").next().unwrap_or("a value");
+        ParseError::new(input, token, expected)
+    })
+}
+
+/// Splits a part of the input at the first occurrence of the delimiter.
+pub fn split_once<'a>(input: &str, s: &'a str, delimiter: &str) -> ParseResult<(&'a str, &'a str)> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(input, s, format!("{delimiter:?}")))
+}
+
+/// Names the file in a parse error, other errors are passed on.
+pub fn with_file(err: Error, file: &Path) -> Error {
+    match err.downcast::<ParseError>() {
+        Ok(err) => Box::new(err.with_file(file)),
+        Err(err) => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_token() {
+        let input = "1 2\n3 x4\n";
+        let err = parse_token::<u32>(input, &input[6..8]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.to_string(), "2:3: expected u32, found \"x4\"");
+    }
+
+    #[test]
+    fn shows_carriage_return_and_file() {
+        let input = "12\r\n";
+        let err = parse_token::<u8>(input, input.split('\n').next().unwrap())
+            .unwrap_err()
+            .with_file(Path::new("input"));
+        assert_eq!(err.to_string(), "input:1:1: expected u8, found \"12\\r\"");
+    }
+
+    #[test]
+    fn reports_missing_delimiter() {
+        let input = "a: b\nc d\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = split_once(input, line, ":").unwrap_err();
+        assert_eq!(err.to_string(), "2:1: expected \":\", found \"c d\"");
+    }
+}
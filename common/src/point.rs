@@ -0,0 +1,217 @@
+//! Points on a plane or in space, and the four directions to move in on a grid.
+//!
+//! On a grid, `y` grows downwards, so [`Dir::Up`] decreases it.
+use std::{
+    fmt::Display,
+    ops::{Add, Mul, Sub},
+};
+
+use Dir::{Down, Left, Right, Up};
+
+/// Integer types usable as coordinates.
+pub trait Scalar:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ONE: Self;
+
+    /// Stays within the type, unlike the signed integers' `abs_diff`.
+    fn abs_diff(self, other: Self) -> Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ONE: Self = 1;
+
+                fn abs_diff(self, other: Self) -> Self {
+                    self.max(other) - self.min(other)
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+scalar!(i32, i64, isize, u32, u64, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Component-wise `+` and `-`, `*` scales by a scalar, and the distances between two points.
+macro_rules! point {
+    ($point:ident { $($c:ident),* }) => {
+        impl<T> $point<T> {
+            pub const fn new($($c: T),*) -> $point<T> {
+                $point { $($c),* }
+            }
+        }
+
+        impl<T: Scalar> $point<T> {
+            pub fn manhattan(self, other: $point<T>) -> T {
+                [$(self.$c.abs_diff(other.$c)),*].into_iter().reduce(|a, b| a + b).unwrap()
+            }
+
+            /// Orders points like the euclidean distance, without leaving the integers.
+            pub fn dist_squared(self, other: $point<T>) -> T {
+                [$(self.$c.abs_diff(other.$c)),*]
+                    .into_iter()
+                    .map(|d| d * d)
+                    .reduce(|a, b| a + b)
+                    .unwrap()
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = $point<T>;
+
+            fn add(self, other: $point<T>) -> $point<T> {
+                $point { $($c: self.$c + other.$c),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = $point<T>;
+
+            fn sub(self, other: $point<T>) -> $point<T> {
+                $point { $($c: self.$c - other.$c),* }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = $point<T>;
+
+            fn mul(self, factor: T) -> $point<T> {
+                $point { $($c: self.$c * factor),* }
+            }
+        }
+    };
+}
+
+point!(Point { x, y });
+point!(Point3 { x, y, z });
+
+impl<T: Scalar> Point<T> {
+    /// The neighbouring point in the direction, `None` if it does not fit into `T`,
+    /// like above the top row of a grid.
+    pub fn step(self, dir: Dir) -> Option<Point<T>> {
+        let Point { x, y } = self;
+        Some(match dir {
+            Up => Point::new(x, y.checked_sub(T::ONE)?),
+            Right => Point::new(x.checked_add(T::ONE)?, y),
+            Down => Point::new(x, y.checked_add(T::ONE)?),
+            Left => Point::new(x.checked_sub(T::ONE)?, y),
+        })
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// Clockwise, starting upwards.
+    pub const ALL: [Dir; 4] = [Up, Right, Down, Left];
+
+    pub fn turn_right(self) -> Dir {
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Dir {
+        self.turn_right().reverse()
+    }
+
+    pub fn reverse(self) -> Dir {
+        self.turn_right().turn_right()
+    }
+}
+
+/// Reads the arrows `^`, `>`, `v` and `<`.
+impl TryFrom<char> for Dir {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Dir, char> {
+        match c {
+            '^' => Ok(Up),
+            '>' => Ok(Right),
+            'v' => Ok(Down),
+            '<' => Ok(Left),
+            _ => Err(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_with_points() {
+        let a = Point::new(1_i64, 2);
+        let b = Point::new(4, -2);
+        assert_eq!(a + (b - a) * 2, Point::new(7, -6));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.dist_squared(b), 25);
+        assert_eq!(
+            Point3::new(1_u64, 2, 3).dist_squared(Point3::new(3, 2, 1)),
+            8
+        );
+    }
+
+    #[test]
+    fn steps_checked() {
+        let origin = Point::new(0_usize, 0);
+        assert_eq!(origin.step(Up), None);
+        assert_eq!(origin.step(Left), None);
+        assert_eq!(origin.step(Down), Some(Point::new(0, 1)));
+        assert_eq!(Point::new(usize::MAX, 0).step(Right), None);
+    }
+
+    #[test]
+    fn rotates() {
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(Left.reverse(), Right);
+        assert_eq!(Dir::try_from('v'), Ok(Down));
+    }
+}
@@ -0,0 +1,196 @@
+//! Showing successive states of a puzzle in the terminal, like the waves of a simulation.
+//!
+//! The binaries of some days show their states if [`VISUAL_VAR`] is set, either to the delay
+//! between frames in milliseconds or to `step`. While the frames are shown, lines typed on stdin
+//! control them: an empty line pauses, or shows the next frame while paused, `c` continues and
+//! `q` skips the remaining frames of the sequence.
+use std::{
+    fmt::Display,
+    io::BufRead,
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    time::Duration,
+};
+
+pub const VISUAL_VAR: &str = "AOC_VISUAL";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Grey,
+}
+
+impl Colour {
+    /// Colours to tell apart successive things, like the files on a disk.
+    pub const CYCLE: [Colour; 6] = [
+        Colour::Red,
+        Colour::Green,
+        Colour::Yellow,
+        Colour::Blue,
+        Colour::Magenta,
+        Colour::Cyan,
+    ];
+
+    fn code(self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::Grey => 90,
+        }
+    }
+}
+
+/// The text in the colour, for terminals understanding ANSI escape codes.
+pub fn paint(text: impl Display, colour: Colour) -> String {
+    format!("\x1b[{}m{text}\x1b[0m", colour.code())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    /// Pauses, or shows the next frame while paused.
+    Step,
+    Continue,
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Option<Command> {
+        match line.trim() {
+            "" => Some(Command::Step),
+            "c" => Some(Command::Continue),
+            "q" => Some(Command::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Shows frames one after another on a cleared terminal.
+pub struct Visualiser {
+    delay: Duration,
+    paused: bool,
+    quit: bool,
+    frame: usize,
+    commands: Receiver<Command>,
+}
+
+impl Visualiser {
+    /// Shows a frame every `delay` until paused.
+    pub fn new(delay: Duration) -> Visualiser {
+        let (sender, commands) = mpsc::channel();
+        // reads until stdin is closed, which may outlive the visualiser
+        std::thread::spawn(move || {
+            for line in std::io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if let Some(command) = Command::parse(&line)
+                    && sender.send(command).is_err()
+                {
+                    break;
+                }
+            }
+        });
+        Visualiser {
+            delay,
+            paused: false,
+            quit: false,
+            frame: 0,
+            commands,
+        }
+    }
+
+    /// Starts paused, so that every frame waits for an empty line.
+    pub fn stepping() -> Visualiser {
+        let mut visualiser = Visualiser::new(Duration::from_millis(200));
+        visualiser.paused = true;
+        visualiser
+    }
+
+    /// Configured by [`VISUAL_VAR`], `None` if it is not set or neither `step` nor a number.
+    pub fn from_env() -> Option<Visualiser> {
+        match std::env::var(VISUAL_VAR).ok()?.trim() {
+            "step" => Some(Visualiser::stepping()),
+            millis => millis
+                .parse()
+                .ok()
+                .map(|millis| Visualiser::new(Duration::from_millis(millis))),
+        }
+    }
+
+    /// Starts another sequence of frames, which is shown even if the previous one was skipped.
+    pub fn restart(&mut self) {
+        self.quit = false;
+        self.frame = 0;
+    }
+
+    /// Clears the terminal, shows the frame below the title and waits for the next one.
+    /// Returns `false` once the frames are skipped, without showing anything.
+    pub fn show(&mut self, title: impl Display, frame: impl Display) -> bool {
+        if self.quit {
+            return false;
+        }
+        self.frame += 1;
+        let hint = match self.paused {
+            true => "enter: next, c: continue, q: skip",
+            false => "enter: pause, q: skip",
+        };
+        print!("\x1b[2J\x1b[H");
+        println!("{title} - frame {} ({hint})", self.frame);
+        println!("{frame}");
+        self.wait();
+        !self.quit
+    }
+
+    fn wait(&mut self) {
+        loop {
+            let command = match self.paused {
+                true => self
+                    .commands
+                    .recv()
+                    .map_err(|_| RecvTimeoutError::Disconnected),
+                false => self.commands.recv_timeout(self.delay),
+            };
+            match command {
+                Ok(Command::Step) if self.paused => return,
+                Ok(Command::Step) => self.paused = true,
+                Ok(Command::Continue) => {
+                    self.paused = false;
+                    return;
+                }
+                Ok(Command::Quit) => {
+                    self.quit = true;
+                    return;
+                }
+                Err(RecvTimeoutError::Timeout) => return,
+                // without stdin, there is nobody to pause or to continue
+                Err(RecvTimeoutError::Disconnected) => {
+                    if !self.paused {
+                        std::thread::sleep(self.delay);
+                    }
+                    self.paused = false;
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_commands() {
+        assert_eq!(Command::parse("\n"), Some(Command::Step));
+        assert_eq!(Command::parse("c"), Some(Command::Continue));
+        assert_eq!(Command::parse(" q "), Some(Command::Quit));
+        assert_eq!(Command::parse("x"), None);
+        assert_eq!(paint(7, Colour::Red), "\x1b[31m7\x1b[0m");
+    }
+}